@@ -0,0 +1,155 @@
+/// Try `f` and, if it fails, try `g` with the same arguments.
+///
+/// Returns the first `Ok` or the `Err` of `g` if both functions failed.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::first_ok;
+///
+/// let parse = first_ok(
+///     |s: &str| s.parse::<i32>(),
+///     |s: &str| i32::from_str_radix(s.trim_start_matches("0x"), 16),
+/// );
+///
+/// assert_eq!(parse("17"), Ok(17));
+/// assert_eq!(parse("0xff"), Ok(255));
+/// assert!(parse("what").is_err());
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::first_ok`]
+///
+/// [`FnExt::first_ok`]: crate::unstable::FnExt::first_ok
+#[inline]
+pub fn first_ok<A, T, E, F, G>(f: F, g: G) -> FirstOk<F, G>
+where
+    F: FnOnce<A, Output = Result<T, E>>,
+    G: FnOnce<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    FirstOk::new(f, g)
+}
+
+/// Represents fallible function `F` with fallback `G`.
+///
+/// For documentation see [`first_ok`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FirstOk<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> FirstOk<F, G> {
+    /// Creates function that tries `f` and then `g`.
+    ///
+    /// It's preferred to use [`first_ok`] instead.
+    #[inline]
+    pub fn new<A, T, E>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A, Output = Result<T, E>>,
+        G: FnOnce<A, Output = Result<T, E>>,
+        A: Clone,
+    {
+        FirstOk { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let FirstOk { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let FirstOk { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, T, E, F, G> FnOnce<A> for FirstOk<F, G>
+where
+    F: FnOnce<A, Output = Result<T, E>>,
+    G: FnOnce<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let FirstOk { f, g } = self;
+        match f.call_once(args.clone()) {
+            Ok(res) => Ok(res),
+            Err(_) => g.call_once(args),
+        }
+    }
+}
+
+impl<A, T, E, F, G> FnMut<A> for FirstOk<F, G>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    G: FnMut<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let FirstOk { f, g } = self;
+        match f.call_mut(args.clone()) {
+            Ok(res) => Ok(res),
+            Err(_) => g.call_mut(args),
+        }
+    }
+}
+
+impl<A, T, E, F, G> Fn<A> for FirstOk<F, G>
+where
+    F: Fn<A, Output = Result<T, E>>,
+    G: Fn<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let FirstOk { f, g } = self;
+        match f.call(args.clone()) {
+            Ok(res) => Ok(res),
+            Err(_) => g.call(args),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::unstable::FnExt;
+
+    #[test]
+    fn cascade() {
+        let calls = Cell::new(0);
+        let fail = |name: &'static str| {
+            let calls = &calls;
+            move |a: i32, b: i32| -> Result<i32, String> {
+                calls.set(calls.get() + 1);
+                Err(format!("{} failed on {}, {}", name, a, b))
+            }
+        };
+
+        let fun = fail("first")
+            .first_ok(fail("second"))
+            .first_ok(|a: i32, b: i32| Ok(a + b));
+
+        assert_eq!(fun(2, 3), Ok(5));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn last_err() {
+        let fun = (|_: i32| Err::<(), _>("first"))
+            .first_ok(|_| Err("second"))
+            .first_ok(|_| Err("third"));
+
+        assert_eq!(fun(0), Err("third"));
+    }
+}
@@ -0,0 +1,168 @@
+use core::iter::Zip;
+
+/// Extensions for iterators (implemented by default for all iterators).
+pub trait IterExt: Iterator + Sized {
+    /// Map every element through `f` and then `g`.
+    ///
+    /// Same as `.map(chain(f, g))` or `.map(f).map(g)`, but in one adapter.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::iter::IterExt;
+    ///
+    /// let vec = vec!["1", "2", "3"];
+    ///
+    /// let res: Vec<_> = vec
+    ///     .iter()
+    ///     .map_chain(|s| s.parse::<i32>().unwrap(), |x| x * 10)
+    ///     .collect();
+    /// let manual: Vec<_> = vec
+    ///     .iter()
+    ///     .map(|s| s.parse::<i32>().unwrap())
+    ///     .map(|x| x * 10)
+    ///     .collect();
+    ///
+    /// assert_eq!(res, [10, 20, 30]);
+    /// assert_eq!(res, manual);
+    /// ```
+    #[inline]
+    fn map_chain<B, C, F, G>(self, f: F, g: G) -> MapChain<Self, F, G>
+    where
+        F: FnMut(Self::Item) -> B,
+        G: FnMut(B) -> C,
+    {
+        MapChain { iter: self, f, g }
+    }
+}
+
+impl<I: Iterator> IterExt for I {
+    // use default definitions...
+}
+
+/// Iterator that maps elements of `I` through `F` and then `G`.
+///
+/// For documentation see [`IterExt::map_chain`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone, Copy)]
+pub struct MapChain<I, F, G> {
+    iter: I,
+    f: F,
+    g: G,
+}
+
+impl<I, B, C, F, G> Iterator for MapChain<I, F, G>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> B,
+    G: FnMut(B) -> C,
+{
+    type Item = C;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let MapChain { iter, f, g } = self;
+        iter.next().map(|x| g(f(x)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<I, B, C, F, G> DoubleEndedIterator for MapChain<I, F, G>
+where
+    I: DoubleEndedIterator,
+    F: FnMut(I::Item) -> B,
+    G: FnMut(B) -> C,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let MapChain { iter, f, g } = self;
+        iter.next_back().map(|x| g(f(x)))
+    }
+}
+
+impl<I, B, C, F, G> ExactSizeIterator for MapChain<I, F, G>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item) -> B,
+    G: FnMut(B) -> C,
+{
+}
+
+/// Combine elements of two iterators with a binary function.
+///
+/// Returns iterator that yields `f(x, y)` for every pair of elements `x` of
+/// `a` and `y` of `b`, stopping when either of them is exhausted. Same as
+/// `a.zip(b).map(|(x, y)| f(x, y))`, but `f` takes two arguments, so e.g.
+/// `i32::add` can be passed directly.
+///
+/// ## Examples
+///
+/// ```
+/// use fntools::iter::zip_with;
+/// use std::ops::Add;
+///
+/// let res: Vec<i32> = zip_with([1, 2, 3], [10, 20, 30], i32::add).collect();
+///
+/// assert_eq!(res, [11, 22, 33]);
+/// ```
+///
+/// ```
+/// use fntools::iter::zip_with;
+///
+/// let names = ["Alice", "Bob", "Carol"];
+/// let ages = vec![31, 17];
+///
+/// let res: Vec<_> = zip_with(&names, ages, |n, a| format!("{} ({})", n, a)).collect();
+///
+/// assert_eq!(res, ["Alice (31)", "Bob (17)"]);
+/// ```
+#[inline]
+pub fn zip_with<I, J, R, F>(a: I, b: J, f: F) -> ZipWith<I::IntoIter, J::IntoIter, F>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    F: FnMut(I::Item, J::Item) -> R,
+{
+    ZipWith {
+        iter: a.into_iter().zip(b),
+        f,
+    }
+}
+
+/// Iterator that combines elements of `I` and `J` with `F`.
+///
+/// For documentation see [`zip_with`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct ZipWith<I, J, F> {
+    iter: Zip<I, J>,
+    f: F,
+}
+
+impl<I, J, R, F> Iterator for ZipWith<I, J, F>
+where
+    I: Iterator,
+    J: Iterator,
+    F: FnMut(I::Item, J::Item) -> R,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ZipWith { iter, f } = self;
+        iter.next().map(|(x, y)| f(x, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<I, J, R, F> ExactSizeIterator for ZipWith<I, J, F>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+    F: FnMut(I::Item, J::Item) -> R,
+{
+}
@@ -39,6 +39,16 @@
 //! This will add [`unstable`] module with all the APIs which use
 //! nightly-only unstable API.
 //!
+//! ## `no_std`
+//!
+//! This library supports `no_std`: disable the default `"std"` feature to
+//! depend only on `core`. Things that need an allocator (e.g. [`BoxFn`]) are
+//! available with `"alloc"` feature:
+//! ```toml
+//! // Cargo.toml
+//! fntools = { version = "0.1.0", default-features = false, features = ["alloc"] }
+//! ```
+//!
 //! ## Unstable API
 //!
 //! Unstable API provides these features:
@@ -64,6 +74,7 @@
 //! [github]: https://github.com/WaffleLapkin/fntools
 //!
 //! [`unstable`]: crate::unstable
+//! [`BoxFn`]: crate::BoxFn
 //!
 //! [`Chain::into_inner`]: crate::unstable::Chain::into_inner
 //! [`.chain`]: crate::unstable::FnExt::chain
@@ -75,7 +86,9 @@
 //! [Wiki: Function Composition]: https://en.wikipedia.org/wiki/Function_composition
 //! [rossetacode.org: Function Composition]: https://rosettacode.org/wiki/Function_composition#Rust
 //! [stackoverflow: How to compose functions in Rust?]: https://stackoverflow.com/questions/45786955/how-to-compose-functions-in-rust
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(unboxed_closures, fn_traits))]
+#![cfg_attr(feature = "visualize", feature(min_specialization))]
 #![doc(html_favicon_url = "https://raw.githubusercontent.com/WaffleLapkin/fntools/dev/icon.ico")]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/WaffleLapkin/fntools/dev/logo.svg")]
 // I want explicit `Fn(Arg) -> ()`
@@ -87,6 +100,9 @@
     broken_intra_doc_links
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 /// Helper macros these are used in this lib
 mod local_macros;
@@ -95,51 +111,188 @@ mod macro_def;
 /// 'Sealed' trait that prevents implementing tuple traits in other crates
 mod sealed;
 
+/// Combinators for fallible functions
+#[cfg(feature = "nightly")]
+mod fallible;
+
+/// Extensions for iterators
+pub mod iter;
+
+/// Builder for multi-stage functions
+pub mod pipeline;
+
+/// Minimal synchronous `Service` interface for functions
+///
+/// **NOTE**: this module is under `#[cfg(feature = "service")]`
+#[cfg(feature = "service")]
+pub mod service;
+
+/// Function stages that can be replaced at runtime
+///
+/// **NOTE**: this module is under `#[cfg(feature = "std")]`
+#[cfg(feature = "std")]
+pub mod swap;
+
 /// Helper module for moving stable thing to dedicated dir
 mod stable {
+    pub mod ap;
+    #[cfg(feature = "alloc")]
+    pub mod boxed;
+    #[cfg(feature = "std")]
+    pub mod catch;
     pub mod chain;
     pub mod compose;
+    /// Functions returning constants.
+    pub mod constant;
+    #[cfg(feature = "alloc")]
+    pub mod curry;
+    pub mod every_nth;
     pub mod flip;
+    pub mod guard;
+    pub mod iterate;
+    pub mod join;
+    pub mod lift;
+    pub mod map_err;
+    pub mod on;
     pub mod product;
+    pub mod recover;
+    #[cfg(feature = "alloc")]
+    pub mod select;
+    pub mod supply;
+    pub mod transpose;
+    pub mod tuple_args;
     /// Unit function output.
     pub mod unit;
     /// Extensions for all types
     pub mod value;
+    pub mod with_env;
+    pub mod with_state;
 }
 
+#[cfg(feature = "std")]
+pub use stable::catch::{catch, catch_once};
 pub use stable::{
+    ap::{ap, ap_mut, ap_once},
     chain::{chain, chain_mut, chain_once},
-    compose::{compose, compose_mut, compose_once},
+    compose::{compose, compose_fn, compose_mut, compose_once, ComposeFn},
+    constant,
+    every_nth::every_nth,
     flip::{flip, flip_mut, flip_once},
-    product::{product, product_mut, product_once},
-    unit::{unit, unit_mut, unit_once},
+    guard::{guard, guard_mut},
+    iterate::{iterate, iterate_mut},
+    join::{join, join_mut, join_once},
+    lift::{
+        lift_option, lift_option_mut, lift_option_once, lift_result, lift_result_mut,
+        lift_result_once,
+    },
+    map_err::{map_err, map_err_mut, map_err_once},
+    on::{on, on_mut},
+    product::{
+        product, product3, product3_mut, product3_once, product_mut, product_once, split,
+        split_mut, split_once,
+    },
+    recover::{recover, recover_mut, recover_once},
+    supply::{supply_2, supply_3},
+    transpose::{
+        transpose_option, transpose_option_mut, transpose_option_once, transpose_result,
+        transpose_result_mut, transpose_result_once,
+    },
+    tuple_args::{tuple_args_2, tuple_args_3},
+    unit::{const_with, const_with_mut, const_with_once, unit, unit_mut, unit_once},
     value,
+    with_env::{with_env, with_env_mut, with_env_once},
+    with_state::with_state,
+};
+#[cfg(feature = "alloc")]
+pub use stable::{
+    boxed::{boxfn, BoxFn},
+    chain::chain_all,
+    compose::compose_all,
+    curry::curry2,
+    select::select,
 };
 
 /// Features that uses nightly-only unstable API
 #[cfg(feature = "nightly")]
 pub mod unstable {
+    pub use crate::fallible::{first_ok, FirstOk};
+
+    #[cfg(feature = "std")]
+    pub use self::cache::{cache_n, memoize, CacheN, Memoize};
+    #[cfg(feature = "parallel")]
+    pub use self::fanout::{par_fanout, ParFanout};
+    #[cfg(feature = "std")]
+    pub use self::timed::{timed, Timed};
     pub use self::{
+        assert_output::{debug_assert_output, DebugAssertOutput},
+        async_compose::{async_chain, async_compose, AsyncChain, AsyncChainFuture},
+        cache_last::{cache_last, CacheLast},
         chain::{chain, Chain},
+        composable::{composable, Composable},
         compose::{compose, Compose},
-        curry::{curry, Curry},
+        constant::{constant, Const},
+        curry::{curry, curry_n, Curry, CurryN},
+        default_arg::{with_default_arg, WithDefaultArg},
+        discard::{discard, Discard},
         ext::FnExt,
+        fanout::{converge, fanout, Converge, Fanout},
+        filter_output::{filter_output, FilterOutput},
+        fix::{fix, Fix},
+        flatten_result::{flatten_result, FlattenResult},
         flip::{flip, Flip},
-        supply::{supply, Supply},
+        map_err::{map_err, MapErr},
+        map_tuple_output::{map_tuple_output, MapTupleOutput},
+        retry::{retry, retry_with, Retry, RetryWith},
+        supply::{supply, supply_right, Supply, SupplyRight},
+        tap::{inspect_args, tap, InspectArgs, Tap},
+        times::{times, Times},
+        uncurry::{uncurry, Uncurry},
         unit::{unit, Unit},
-        untuple::{untuple, Untuple},
+        untuple::{spread, untuple, untuple_flat, Untuple, UntupleFlat},
         value::ValueExtUnstable,
     };
+    #[cfg(feature = "alloc")]
+    pub use self::{
+        boxed::DynFn,
+        counted::{counted, Counted},
+    };
 
+    mod assert_output;
+    mod async_compose;
+    #[cfg(feature = "alloc")]
+    mod boxed;
+    #[cfg(feature = "std")]
+    mod cache;
+    mod cache_last;
     mod chain;
+    mod composable;
     mod compose;
+    mod constant;
+    #[cfg(feature = "alloc")]
+    mod counted;
     mod curry;
+    mod default_arg;
+    mod discard;
     mod ext;
+    mod fanout;
+    mod filter_output;
+    mod fix;
+    mod flatten_result;
     mod flip;
+    mod map_err;
+    mod map_tuple_output;
+    mod retry;
     mod supply;
+    mod tap;
+    #[cfg(feature = "std")]
+    mod timed;
+    mod times;
+    mod uncurry;
     mod unit;
     mod untuple;
     mod value;
+    #[cfg(feature = "visualize")]
+    mod visualize;
 }
 
 /// Helpers for working with tuples
@@ -162,12 +315,28 @@ pub mod tuple {
     /// **NOTE**: this module is under `#[cfg(feature = "concat")]`
     #[cfg(feature = "concat")]
     pub mod concat;
+    /// Collect homogeneous tuple into `Vec` removing consecutive duplicates
+    /// (`(A, A, B) => vec![A, B]`)
+    ///
+    /// **NOTE**: this module is under `#[cfg(feature = "alloc")]`
+    #[cfg(feature = "alloc")]
+    pub mod dedup;
+    /// Flatten nested tuple (`((A, B), C) => (A, B, C)`)
+    pub mod flatten;
     /// Flip tuple (`(A, B) => (B, A)`)
     pub mod flip;
+    /// Number of elements in tuple (`(A, B) => 2`)
+    pub mod len;
+    /// Map homogeneous tuple (`(A, A) => (B, B)`)
+    pub mod map;
     /// Pop element from tuple (`(A, B, T) => ((A, B), T)`)
     pub mod pop;
     /// Push element to tuple (`(A, B) + T => (A, B, T)`)
     pub mod push;
+    /// Running accumulation over homogeneous tuple (`(1, 2, 3) => (1, 3, 6)`)
+    pub mod scan;
     /// Take element from tuple (`(T, A, B) => (T, (A, B))`)
     pub mod take;
+    /// Take element from the end of tuple (`(A, B, T) => (T, (A, B))`)
+    pub mod take_last;
 }
@@ -25,3 +25,14 @@ macro_rules! last_type {
     ($a:ident,) => { $a };
     ($a:ident, $($rest_a:ident,)+) => { last_type!($($rest_a,)+) };
 }
+
+/// Replace identifier with a type
+///
+/// `replace_ident!(A, T)` expands to `T`, this is useful to make homogeneous
+/// tuples (e.g. `($( replace_ident!($types, T), )*)`) in `for_tuples`
+/// callbacks.
+macro_rules! replace_ident {
+    ($_ident:ident, $ty:ty) => {
+        $ty
+    };
+}
@@ -60,6 +60,106 @@ macro_rules! chain_many {
     };
 }
 
+/// Pipes value through many functions (left-to-right).
+///
+/// `pipe!(f, g, h)` is the same as `chain(f, chain(g, h))` (see
+/// [`chain_many`](crate::chain_many)).
+///
+/// ```
+/// use fntools::{chain, pipe};
+///
+/// let parse = |s: &str| s.parse::<i32>().unwrap();
+/// let add_one = |x: i32| x + 1;
+/// let double = |x: i32| x * 2;
+/// let show = |x: i32| format!("<{}>", x);
+///
+/// // execution order: parse -> add_one -> double -> show
+/// let fun = pipe!(parse, add_one, double, show);
+///
+/// assert_eq!(fun("20"), "<42>");
+/// assert_eq!(
+///     fun("20"),
+///     chain(parse, chain(add_one, chain(double, show)))("20")
+/// );
+/// ```
+#[macro_export]
+macro_rules! pipe {
+    ($head:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::chain_many!($head, $( $tail ),+)
+    };
+}
+
+/// Composes many functions (right-to-left).
+///
+/// `compose!(f, g, h)` is the same as `compose(f, compose(g, h))` (see
+/// [`compose_many`](crate::compose_many)).
+///
+/// ```
+/// use fntools::compose;
+///
+/// let to_16 = |i: i8| i16::from(i);
+/// let to_32 = |i: i16| i32::from(i);
+/// let to_64 = |i: i32| i64::from(i);
+///
+/// // execution order: to_16 -> to_32 -> to_64
+/// let i8_to_i64 = compose!(to_64, to_32, to_16);
+///
+/// assert_eq!(i8_to_i64(8i8), 8i64);
+/// assert_eq!(i8_to_i64(8i8), compose(compose(to_64, to_32), to_16)(8i8));
+/// ```
+#[macro_export]
+macro_rules! compose {
+    ($head:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::compose_many!($head, $( $tail ),+)
+    };
+}
+
+/// Data flows left-to-right: `flow!(f, g, h)` calls `f`, then `g`, then `h`.
+///
+/// This is the same as [`pipe`](crate::pipe)/[`chain_many`](crate::chain_many),
+/// the name just makes the direction explicit. See [`rflow`](crate::rflow) for
+/// the right-to-left version.
+///
+/// ```
+/// use fntools::flow;
+///
+/// let f = |x: i32| x + 1;
+/// let g = |x: i32| x * 10;
+///
+/// assert_eq!(flow!(f, g)(4), g(f(4)));
+/// assert_eq!(flow!(f, g)(4), 50);
+/// assert_eq!(flow!(f, g, f)(4), 51);
+/// ```
+#[macro_export]
+macro_rules! flow {
+    ($head:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::chain_many!($head, $( $tail ),+)
+    };
+}
+
+/// Data flows right-to-left: `rflow!(f, g, h)` calls `h`, then `g`, then `f`.
+///
+/// This is the same as [`compose_many`](crate::compose_many), the name just
+/// makes the direction explicit. See [`flow`](crate::flow) for the
+/// left-to-right version.
+///
+/// ```
+/// use fntools::rflow;
+///
+/// let f = |x: i32| x + 1;
+/// let g = |x: i32| x * 10;
+///
+/// assert_eq!(rflow!(f, g)(4), f(g(4)));
+/// assert_eq!(rflow!(f, g)(4), 41);
+/// assert_eq!(rflow!(f, g, f)(4), 51);
+/// ```
+#[macro_export]
+macro_rules! rflow {
+    ($head:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::compose_many!($head, $( $tail ),+)
+    };
+}
+
 /// Same as [`compose_many`](crate::compose_many), but this macro uses
 /// `fntools::chain_once`
 #[macro_export]
@@ -173,3 +273,52 @@ macro_rules! compose_many_mut {
         $crate::compose_mut($head, $crate::compose_many_mut!( $( $tail ),+ ))
     };
 }
+
+/// Fans out one argument to many functions with the same output type,
+/// collecting results into an array.
+///
+/// `fork_array!(f, g, h)` is the same as
+/// `|a| [f(a.clone()), g(a.clone()), h(a.clone())]`, so the argument must
+/// implement [`Clone`]. From 2 to 8 functions are supported.
+///
+/// ```
+/// use fntools::fork_array;
+///
+/// let double = |x: i32| x * 2;
+/// let triple = |x: i32| x * 3;
+/// let negate = |x: i32| -x;
+///
+/// let fun = fork_array!(double, triple, negate);
+///
+/// assert_eq!(fun(5), [10, 15, -5]);
+/// assert_eq!(fun(5).iter().sum::<i32>(), 20);
+/// ```
+///
+/// Input is cloned for every branch:
+/// ```
+/// use fntools::fork_array;
+///
+/// let fun = fork_array!(
+///     |s: String| s.len(),
+///     |s: String| s.chars().count(),
+///     |s: String| s.split_whitespace().count(),
+/// );
+///
+/// assert_eq!(fun(String::from("всем привет")), [21, 11, 2]);
+/// ```
+#[macro_export]
+macro_rules! fork_array {
+    // Give every function a name, then build the closure
+    (@zip [$( ($name:ident $fun:expr) )*] [$( $names:ident )*] []) => {{
+        $( let $name = $fun; )*
+        move |a| [$( $name(::core::clone::Clone::clone(&a)) ),*]
+    }};
+
+    (@zip [$( $done:tt )*] [$name:ident $( $names:ident )*] [$fun:expr $(, $funs:expr )*]) => {
+        $crate::fork_array!(@zip [$( $done )* ($name $fun)] [$( $names )*] [$( $funs ),*])
+    };
+
+    ($first:expr, $( $rest:expr ),+ $(,)?) => {
+        $crate::fork_array!(@zip [] [f0 f1 f2 f3 f4 f5 f6 f7] [$first, $( $rest ),+])
+    };
+}
@@ -0,0 +1,96 @@
+use crate::{chain, map_err};
+
+/// Start a pipeline with function `f`.
+///
+/// [`Pipeline`] is a builder-style alternative to nested [`chain`] calls:
+/// stages are added one by one with methods and the resulting function is
+/// obtained with [`build`](Pipeline::build).
+///
+/// # Examples
+/// ```
+/// use fntools::pipeline::pipeline;
+/// use std::{cell::Cell, num::ParseIntError};
+///
+/// let calls = Cell::new(0);
+/// let fun = pipeline(|s: &str| s.parse::<i32>())
+///     .tap(|_: &Result<i32, ParseIntError>| calls.set(calls.get() + 1))
+///     .map_err(|e: ParseIntError| e.to_string())
+///     .then(|res: Result<i32, String>| res.map(|x| x * 2))
+///     .build();
+///
+/// assert_eq!(fun("21"), Ok(42));
+/// assert_eq!(fun("x"), Err(String::from("invalid digit found in string")));
+/// assert_eq!(calls.get(), 2);
+/// ```
+#[inline]
+pub fn pipeline<A, B, F>(f: F) -> Pipeline<F>
+where
+    F: Fn(A) -> B,
+{
+    Pipeline { f }
+}
+
+/// Function built stage by stage.
+///
+/// For documentation see [`pipeline`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Pipeline<F> {
+    f: F,
+}
+
+impl<F> Pipeline<F> {
+    /// Add stage `g` which is called with the output of the pipeline.
+    ///
+    /// See also: [`chain`]
+    #[inline]
+    pub fn then<A, B, C, G>(self, g: G) -> Pipeline<impl Fn(A) -> C>
+    where
+        F: Fn(A) -> B,
+        G: Fn(B) -> C,
+    {
+        Pipeline {
+            f: chain(self.f, g),
+        }
+    }
+
+    /// Map error of the fallible pipeline with `m`.
+    ///
+    /// See also: [`map_err`]
+    #[inline]
+    pub fn map_err<A, T, E, E2, M>(self, m: M) -> Pipeline<impl Fn(A) -> Result<T, E2>>
+    where
+        F: Fn(A) -> Result<T, E>,
+        M: Fn(E) -> E2,
+    {
+        Pipeline {
+            f: map_err(self.f, m),
+        }
+    }
+
+    /// Inspect output of the pipeline by reference, leaving it unchanged.
+    #[inline]
+    pub fn tap<A, B, T>(self, t: T) -> Pipeline<impl Fn(A) -> B>
+    where
+        F: Fn(A) -> B,
+        T: Fn(&B),
+    {
+        let Pipeline { f } = self;
+        Pipeline {
+            f: move |a: A| {
+                let res = f(a);
+                t(&res);
+                res
+            },
+        }
+    }
+
+    /// Returns the function built by the pipeline.
+    #[inline]
+    pub fn build<A, B>(self) -> impl Fn(A) -> B
+    where
+        F: Fn(A) -> B,
+    {
+        self.f
+    }
+}
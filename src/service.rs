@@ -0,0 +1,87 @@
+/// A minimal synchronous [`tower`]-like service, i.e. something that turns
+/// requests into responses.
+///
+/// Use [`FnService`] (or [`.into_service()`] with `nightly` feature) to turn a
+/// function into a service.
+///
+/// [`tower`]: https://docs.rs/tower
+/// [`.into_service()`]: crate::unstable::FnExt::into_service
+pub trait Service<Req> {
+    /// Responses given by the service.
+    type Response;
+
+    /// Process the request and return the response.
+    fn call(&mut self, req: Req) -> Self::Response;
+}
+
+/// Adapter that implements [`Service`] for a function.
+///
+/// ## Examples
+/// ```
+/// use fntools::{
+///     chain,
+///     service::{FnService, Service},
+/// };
+///
+/// fn handle<S: Service<&'static str, Response = usize>>(mut service: S) -> usize {
+///     service.call("hello") + service.call("world!")
+/// }
+///
+/// let len = FnService::new(chain(str::trim, str::len));
+///
+/// assert_eq!(handle(len), 11);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FnService<F> {
+    f: F,
+}
+
+impl<F> FnService<F> {
+    /// Creates service from function `f`.
+    #[inline]
+    pub fn new(f: F) -> Self { FnService { f } }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<F, Req, R> Service<Req> for FnService<F>
+where
+    F: FnMut(Req) -> R,
+{
+    type Response = R;
+
+    #[inline]
+    fn call(&mut self, req: Req) -> Self::Response { (self.f)(req) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chain_mut,
+        service::{FnService, Service},
+    };
+
+    fn call_all<S: Service<i32>>(service: &mut S, reqs: &[i32]) -> Vec<S::Response> {
+        reqs.iter().map(|&req| service.call(req)).collect()
+    }
+
+    #[test]
+    fn chain_as_service() {
+        let mut total = 0;
+        let mut service = FnService::new(chain_mut(
+            |x: i32| x * 2,
+            |x: i32| {
+                total += x;
+                total
+            },
+        ));
+
+        assert_eq!(call_all(&mut service, &[1, 2, 3]), [2, 6, 12]);
+    }
+}
@@ -0,0 +1,70 @@
+/// Apply function produced from the argument to another value produced from
+/// the argument.
+///
+/// Takes functions `f` and `g` and returns `|a: A| f(a.clone())(g(a))` (this
+/// is `<*>` for functions in haskell's `Control.Applicative`, also known as
+/// the S combinator).
+///
+/// This allows writing point-free functions which need several values
+/// derived from the same argument: `f` "reads" the first one from the
+/// argument and returns a function accepting the second one.
+///
+/// [`unstable::converge`] is the same idea with an uncurried combining
+/// function: `ap(f, g)` is `converge(f, g, |h, b| h(b))`, and
+/// `converge(f, g, h)` is `ap(|a| move |b| h(f(a), b), g)`.
+///
+/// # Examples
+/// ```
+/// use fntools::ap;
+///
+/// // `|s| s.len() - s.trim().len()` without naming `s` twice
+/// let padding = ap(
+///     |s: &str| move |trimmed: usize| s.len() - trimmed,
+///     |s: &str| s.trim().len(),
+/// );
+///
+/// assert_eq!(padding("  hi "), 3);
+/// assert_eq!(padding("hi"), 0);
+/// ```
+///
+/// [`unstable::converge`]: crate::unstable::converge
+#[inline]
+pub fn ap<A, B, C, F, G, H>(f: F, g: G) -> impl Fn(A) -> C
+where
+    A: Clone,
+    F: Fn(A) -> H,
+    G: Fn(A) -> B,
+    H: FnOnce(B) -> C,
+{
+    move |a: A| f(a.clone())(g(a))
+}
+
+/// Apply function produced from the argument to another value produced from
+/// the argument, where the functions can be called only once.
+///
+/// See [ap](self::ap) for documentation.
+#[inline]
+pub fn ap_once<A, B, C, F, G, H>(f: F, g: G) -> impl FnOnce(A) -> C
+where
+    A: Clone,
+    F: FnOnce(A) -> H,
+    G: FnOnce(A) -> B,
+    H: FnOnce(B) -> C,
+{
+    move |a: A| f(a.clone())(g(a))
+}
+
+/// Apply function produced from the argument to another value produced from
+/// the argument, where the functions can be called only by unique reference.
+///
+/// See [ap](self::ap) for documentation.
+#[inline]
+pub fn ap_mut<A, B, C, F, G, H>(mut f: F, mut g: G) -> impl FnMut(A) -> C
+where
+    A: Clone,
+    F: FnMut(A) -> H,
+    G: FnMut(A) -> B,
+    H: FnOnce(B) -> C,
+{
+    move |a: A| f(a.clone())(g(a))
+}
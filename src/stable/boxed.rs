@@ -0,0 +1,58 @@
+use alloc::boxed::Box;
+
+/// Boxed function `A -> C`.
+///
+/// Combinators return unnameable `impl Fn` types, this alias gives a stable,
+/// nameable type to return from public functions or store in structs (at the
+/// cost of an allocation and dynamic dispatch).
+///
+/// The lifetime `'a` bounds what the function may borrow, use
+/// `BoxFn<'static, A, C>` for functions that own all of their captures.
+///
+/// **NOTE**: this type is under `#[cfg(feature = "alloc")]`
+///
+/// ## Examples
+/// ```
+/// use fntools::{boxfn, chain, BoxFn};
+///
+/// pub fn words_len() -> BoxFn<'static, String, usize> {
+///     boxfn(chain(
+///         |s: String| s.to_lowercase(),
+///         chain(
+///             |s: String| s.split_whitespace().map(str::len).collect::<Vec<_>>(),
+///             |lens: Vec<usize>| lens.into_iter().sum(),
+///         ),
+///     ))
+/// }
+///
+/// let fun = words_len();
+/// assert_eq!(fun(String::from("Hello   World")), 10);
+/// ```
+///
+/// Returning a function which borrows its environment:
+/// ```
+/// use fntools::{boxfn, BoxFn};
+///
+/// pub fn has_prefix(prefix: &str) -> BoxFn<'_, String, bool> {
+///     boxfn(move |s: String| s.starts_with(prefix))
+/// }
+///
+/// let prefix = String::from("foo");
+/// let fun = has_prefix(&prefix);
+/// assert!(fun(String::from("foobar")));
+/// assert!(!fun(String::from("bar")));
+/// ```
+pub type BoxFn<'a, A, C> = Box<dyn Fn(A) -> C + 'a>;
+
+/// Box a function.
+///
+/// See [`BoxFn`](crate::BoxFn) for documentation.
+///
+/// **NOTE**: this function is under `#[cfg(feature = "alloc")]`
+#[inline]
+pub fn boxfn<'a, A, C, F>(f: F) -> BoxFn<'a, A, C>
+where
+    F: Fn(A) -> C + 'a,
+{
+    Box::new(f)
+}
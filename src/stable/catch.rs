@@ -0,0 +1,62 @@
+use std::{
+    any::Any,
+    panic::{catch_unwind, RefUnwindSafe, UnwindSafe},
+};
+
+/// Catch panics of a function.
+///
+/// Takes function `f` and returns `|a: A| catch_unwind(|| f(a))`, so panics
+/// in `f` are caught and returned as `Err` with the panic payload, instead of
+/// unwinding through the caller.
+///
+/// This is useful to isolate potentially-panicking stages of a pipeline.
+///
+/// Note that panics are only caught if the panic strategy is `unwind` (see
+/// [`catch_unwind`] for more info).
+///
+/// # Examples
+/// ```
+/// use fntools::catch;
+///
+/// let div = catch(|(a, b): (i32, i32)| a / b);
+///
+/// assert_eq!(div((7, 2)).ok(), Some(3));
+/// assert!(div((7, 0)).is_err());
+/// ```
+///
+/// The panic payload can be inspected:
+/// ```
+/// use fntools::catch;
+///
+/// let fun = catch(|x: i32| -> i32 {
+///     if x < 0 {
+///         panic!("negative")
+///     }
+///     x
+/// });
+///
+/// let payload = fun(-1).unwrap_err();
+/// assert_eq!(payload.downcast_ref::<&str>(), Some(&"negative"));
+/// ```
+///
+/// **NOTE**: this function is under `#[cfg(feature = "std")]`
+#[inline]
+pub fn catch<A, R, F>(f: F) -> impl Fn(A) -> Result<R, Box<dyn Any + Send>>
+where
+    F: Fn(A) -> R + RefUnwindSafe,
+    A: UnwindSafe,
+{
+    move |a: A| catch_unwind(|| f(a))
+}
+
+/// Catch panics of a function which can be called only once.
+///
+/// See [catch](self::catch) for documentation.
+#[inline]
+pub fn catch_once<A, R, F>(f: F) -> impl FnOnce(A) -> Result<R, Box<dyn Any + Send>>
+where
+    F: FnOnce(A) -> R + UnwindSafe,
+    A: UnwindSafe,
+{
+    move |a: A| catch_unwind(move || f(a))
+}
@@ -1,3 +1,9 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::BoxFn;
+
 /// Chain two functions.
 ///
 /// Takes functions `f` and `g` and returns `g ∘ f = |a: A| g(f(a))`.
@@ -27,12 +33,23 @@
 /// assert_eq!(i8_to_i64(8i8), 8i64);
 /// ```
 ///
+/// Stages may be generic, `chain` doesn't constrain them beyond `Fn`:
+/// ```
+/// use fntools::chain;
+/// use std::ops::Add;
+///
+/// fn twice<T: Add<Output = T> + Copy>(x: T) -> T { x + x }
+///
+/// assert_eq!(chain(twice, twice)(2), 8);
+/// assert_eq!(chain(twice, twice)(0.25), 1.0);
+/// ```
+///
 /// See also:
 /// - [`unstable::chain`]
 /// - [`fntools::compose`]
 ///
 /// [`unstable::chain`]: crate::unstable::chain
-/// [`fntools::compose`]: crate::compose
+/// [`fntools::compose`]: crate::compose()
 #[inline]
 pub fn chain<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
 where
@@ -65,3 +82,96 @@ where
 {
     move |a: A| g(f(a))
 }
+
+/// Chain a list of functions.
+///
+/// Takes list of functions `fs` and returns function that applies them one by
+/// one in the list order (so `chain_all(vec![f, g, h])` is the same as
+/// `|a: A| h(g(f(a)))`). Empty list gives identity function.
+///
+/// Unlike [`chain`] and [`chain_many!`], the number of functions may be
+/// determined at runtime, but all of them must have the same type `A -> A`.
+///
+/// **NOTE**: this function is under `#[cfg(feature = "alloc")]`
+///
+/// # Examples
+/// ```
+/// use fntools::{boxfn, chain_all, BoxFn};
+///
+/// let steps: Vec<BoxFn<'_, i32, i32>> =
+///     (1..=3).map(|i| boxfn(move |x: i32| x * 10 + i)).collect();
+/// let fun = chain_all(steps);
+///
+/// assert_eq!(fun(0), 123);
+///
+/// let id = chain_all(Vec::new());
+/// assert_eq!(id(17), 17);
+/// ```
+///
+/// See also:
+/// - [`fntools::compose_all`]
+///
+/// [`fntools::compose_all`]: crate::compose_all
+/// [`chain_many!`]: crate::chain_many!
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn chain_all<'a, A: 'a>(fs: Vec<BoxFn<'a, A, A>>) -> impl Fn(A) -> A + 'a {
+    move |a: A| fs.iter().fold(a, |acc, f| f(acc))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Add;
+
+    use crate::{chain, chain_mut, chain_once, compose};
+
+    fn twice<T: Add<Output = T> + Copy>(x: T) -> T { x + x }
+
+    /// `x * 4` for any `T: Add`, built from generic stages
+    fn quadruple<T: Add<Output = T> + Copy>(x: T) -> T { chain(twice, twice)(x) }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Meters(u32);
+
+    impl Add for Meters {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self { Meters(self.0 + rhs.0) }
+    }
+
+    #[test]
+    fn generic_stages() {
+        assert_eq!(quadruple(3i32), 12);
+        assert_eq!(quadruple(1.5f64), 6.0);
+        assert_eq!(quadruple(Meters(2)), Meters(8));
+
+        assert_eq!(chain(twice::<i32>, |x| x + 1)(3), 7);
+        assert_eq!(compose(twice::<f64>, |x| x + 1.0)(0.5), 3.0);
+        assert_eq!(compose(twice, twice)(Meters(1)), Meters(4));
+    }
+
+    #[test]
+    fn generic_closures() {
+        let add = |x: Meters| x + Meters(1);
+
+        assert_eq!(chain_once(add, twice)(Meters(1)), Meters(4));
+        assert_eq!(chain_mut(twice, add)(Meters(1)), Meters(3));
+    }
+
+    #[test]
+    fn fn_items() {
+        // fn items (including methods) are accepted as is, no `as fn(..)` casts
+        assert_eq!(chain(str::trim, str::len)("  hi  "), 2);
+        assert!(chain(<[u8]>::len, usize::is_power_of_two)(b"four"));
+        assert!(compose(char::is_alphabetic, char::from)(b'A'));
+        assert_eq!(
+            chain_once(String::into_bytes, String::from_utf8)(String::from("abc")),
+            Ok(String::from("abc"))
+        );
+
+        // fn pointers work too, e.g. when the stage is chosen at runtime
+        let stages = [str::trim, str::trim_start, str::trim_end];
+        let lens: Vec<_> = stages.iter().map(|&f| chain(f, str::len)(" a ")).collect();
+        assert_eq!(lens, [1, 2, 2]);
+    }
+}
@@ -1,3 +1,11 @@
+use core::fmt::{Debug, Error, Formatter};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::BoxFn;
+
 /// Compose two functions.
 ///
 /// Takes functions `f` and `g` and returns `f ∘ g = |a: A| f(g(a))`.
@@ -22,7 +30,7 @@
 /// let to_64 = |i: i32| i64::from(i);
 ///
 /// // execution order: to_16 -> to_32 -> to_64
-/// let i8_to_i64 = compose(compose(to_64, to_32), to_16);
+/// let i8_to_i64 = compose!(to_64, to_32, to_16);
 ///
 /// assert_eq!(i8_to_i64(8i8), 8i64);
 /// ```
@@ -30,6 +38,7 @@
 /// See also:
 /// - [`unstable::compose`]
 /// - [`fntools::chain`]
+/// - composition of many functions: [`compose!`](crate::compose!)
 ///
 /// [`unstable::compose`]: crate::unstable::compose
 /// [`fntools::chain`]: crate::chain
@@ -65,3 +74,113 @@ where
 {
     move |a: A| f(g(a))
 }
+
+/// Compose a list of functions.
+///
+/// Takes list of functions `fs` and returns function that applies them one by
+/// one in the reversed list order (so `compose_all(vec![f, g, h])` is the
+/// same as `|a: A| f(g(h(a)))`). Empty list gives identity function.
+///
+/// **NOTE**: this function is under `#[cfg(feature = "alloc")]`
+///
+/// # Examples
+/// ```
+/// use fntools::{boxfn, compose_all, BoxFn};
+///
+/// let steps: Vec<BoxFn<'_, i32, i32>> =
+///     (1..=3).map(|i| boxfn(move |x: i32| x * 10 + i)).collect();
+/// let fun = compose_all(steps);
+///
+/// assert_eq!(fun(0), 321);
+///
+/// let id = compose_all(Vec::new());
+/// assert_eq!(id(17), 17);
+/// ```
+///
+/// See also:
+/// - [`fntools::chain_all`]
+///
+/// [`fntools::chain_all`]: crate::chain_all
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn compose_all<'a, A: 'a>(fs: Vec<BoxFn<'a, A, A>>) -> impl Fn(A) -> A + 'a {
+    move |a: A| fs.iter().rev().fold(a, |acc, f| f(acc))
+}
+
+/// Compose two function pointers in const context.
+///
+/// Takes function pointers `f` and `g` and returns [`ComposeFn`] that
+/// represents `f ∘ g`. Unlike [`compose`] this is a `const fn`, so the
+/// composition can be stored in `const`s and `static`s (e.g. a dispatch
+/// table of composed operations).
+///
+/// Closures can't be called in const context, so the composition is called
+/// with the [`call`](ComposeFn::call) method.
+///
+/// # Examples
+/// ```
+/// use fntools::{compose_fn, ComposeFn};
+///
+/// fn double(x: i32) -> i32 { x * 2 }
+/// fn inc(x: i32) -> i32 { x + 1 }
+///
+/// const OPS: [ComposeFn<i32, i32, i32>; 2] = [compose_fn(double, inc), compose_fn(inc, double)];
+///
+/// assert_eq!(OPS[0].call(3), 8);
+/// assert_eq!(OPS[1].call(3), 7);
+/// ```
+///
+/// See also:
+/// - [`compose`]
+#[inline]
+pub const fn compose_fn<A, B, C>(f: fn(B) -> C, g: fn(A) -> B) -> ComposeFn<A, B, C> {
+    ComposeFn { f, g }
+}
+
+/// Composition of function pointers `fn(B) -> C` and `fn(A) -> B`.
+///
+/// For documentation see [`compose_fn`].
+pub struct ComposeFn<A, B, C> {
+    f: fn(B) -> C,
+    g: fn(A) -> B,
+}
+
+impl<A, B, C> ComposeFn<A, B, C> {
+    /// Calls the composition (`f(g(a))`).
+    #[inline]
+    pub fn call(&self, a: A) -> C { (self.f)((self.g)(a)) }
+}
+
+impl<A, B, C> Clone for ComposeFn<A, B, C> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<A, B, C> Copy for ComposeFn<A, B, C> {}
+
+impl<A, B, C> Debug for ComposeFn<A, B, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("ComposeFn")
+            .field("f", &self.f)
+            .field("g", &self.g)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compose_fn, ComposeFn};
+
+    fn len(s: &str) -> usize { s.len() }
+    fn square(x: usize) -> usize { x * x }
+
+    static LEN_SQUARED: ComposeFn<&str, usize, usize> = compose_fn(square, len);
+
+    #[test]
+    fn const_compose_fn() {
+        const SQUARE_TWICE: ComposeFn<usize, usize, usize> = compose_fn(square, square);
+
+        assert_eq!(LEN_SQUARED.call("abc"), 9);
+        assert_eq!(SQUARE_TWICE.call(3), 81);
+    }
+}
@@ -0,0 +1,183 @@
+use core::cell::{Cell, OnceCell};
+
+/// Function that returns `()`.
+///
+/// ## Examples
+/// ```
+/// use fntools::constant::unit_fn;
+///
+/// let nothing = unit_fn();
+///
+/// assert_eq!(nothing(), ());
+/// ```
+#[inline]
+pub fn unit_fn() -> impl Fn() -> () { || () }
+
+/// Function that ignores its argument and returns `()`.
+///
+/// Chaining a function with `const_unit` drops its output (like
+/// [`fntools::unit`], but as a separate stage).
+///
+/// ## Examples
+/// ```
+/// use fntools::{chain, constant::const_unit};
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let check = chain(parse, const_unit());
+///
+/// assert_eq!(check("17"), ());
+/// assert_eq!(const_unit()(String::from("dropped")), ());
+/// ```
+///
+/// See also:
+/// - [`unstable::constant`], which ignores any number of arguments
+///
+/// [`fntools::unit`]: crate::unit
+/// [`unstable::constant`]: crate::unstable::constant
+#[inline]
+pub fn const_unit<A>() -> impl Fn(A) -> () { |_: A| () }
+
+/// Function that ignores its argument and returns `true`.
+///
+/// Useful as a trivial predicate, e.g. as a default filter.
+///
+/// ## Examples
+/// ```
+/// use fntools::constant::const_true;
+///
+/// fn count_evens<P: Fn(i32) -> bool>(v: &[i32], p: P) -> usize {
+///     v.iter().filter(|&&x| x % 2 == 0 && p(x)).count()
+/// }
+///
+/// // `const_true` as a "no additional condition" predicate
+/// assert_eq!(count_evens(&[1, 2, 3, 4], const_true()), 2);
+/// assert_eq!(count_evens(&[1, 2, 3, 4], |x| x > 2), 1);
+/// assert!([1, 2, 3].iter().all(const_true()));
+/// ```
+///
+/// See also:
+/// - [`const_false`]
+#[inline]
+pub fn const_true<A>() -> impl Fn(A) -> bool { |_: A| true }
+
+/// Function that ignores its argument and returns `false`.
+///
+/// ## Examples
+/// ```
+/// use fntools::constant::const_false;
+///
+/// let v = vec![1, 2, 3];
+///
+/// assert!(!v.iter().any(const_false()));
+/// assert_eq!(v.into_iter().position(const_false()), None);
+/// ```
+///
+/// See also:
+/// - [`const_true`]
+#[inline]
+pub fn const_false<A>() -> impl Fn(A) -> bool { |_: A| false }
+
+/// Function that ignores its argument and returns `None`.
+///
+/// ## Examples
+/// ```
+/// use fntools::constant::const_none;
+///
+/// let lookup = const_none::<&str, u32>();
+///
+/// assert_eq!(lookup("key"), None);
+/// assert_eq!(Some("key").and_then(const_none::<_, u32>()), None);
+/// ```
+#[inline]
+pub fn const_none<A, T>() -> impl Fn(A) -> Option<T> { |_: A| None }
+
+/// Function that returns `T::default()`.
+///
+/// Note that [`Default::default`] is called on every call (the value isn't
+/// cached).
+///
+/// ## Examples
+/// ```
+/// use fntools::constant::default_fn;
+///
+/// let zero = default_fn::<i32>();
+/// let empty = default_fn::<String>();
+///
+/// assert_eq!(zero(), 0);
+/// assert_eq!(empty(), String::new());
+///
+/// // As a fallback factory
+/// assert_eq!(None.unwrap_or_else(default_fn::<Vec<u8>>()), []);
+/// ```
+#[inline]
+pub fn default_fn<T>() -> impl Fn() -> T
+where
+    T: Default,
+{
+    T::default
+}
+
+/// Function that computes value with `f` on the first call and returns clones
+/// of it afterwards.
+///
+/// ## Examples
+/// ```
+/// use fntools::constant::lazy_constant;
+/// use std::cell::Cell;
+///
+/// let runs = Cell::new(0);
+/// let config = lazy_constant(|| {
+///     runs.set(runs.get() + 1);
+///     vec![1, 2, 3]
+/// });
+///
+/// assert_eq!(runs.get(), 0); // not computed yet
+/// assert_eq!(config(), [1, 2, 3]);
+/// assert_eq!(config(), [1, 2, 3]);
+/// assert_eq!(runs.get(), 1);
+/// ```
+///
+/// ## Panics
+///
+/// `f` is taken out before it is called, so the returned function panics if
+/// - `f` calls the returned function (re-entrancy), since there is no value yet
+///   and no `f` to compute it
+/// - an earlier call of `f` panicked (and the panic was caught), since `f` is
+///   gone and the value was never computed
+#[inline]
+pub fn lazy_constant<T, F>(f: F) -> impl Fn() -> T
+where
+    T: Clone,
+    F: FnOnce() -> T,
+{
+    let f = Cell::new(Some(f));
+    let value = OnceCell::new();
+
+    move || {
+        value
+            .get_or_init(|| {
+                let f = f
+                    .take()
+                    .expect("`lazy_constant` was called re-entrantly or after `f` panicked");
+                f()
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use crate::constant::lazy_constant;
+
+    #[test]
+    fn lazy_constant_after_panic() {
+        let fun = lazy_constant(|| -> i32 { panic!("boom") });
+
+        assert!(catch_unwind(AssertUnwindSafe(&fun)).is_err());
+        let err = catch_unwind(AssertUnwindSafe(&fun)).unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert!(msg.contains("after `f` panicked"), "{}", msg);
+    }
+}
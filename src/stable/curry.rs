@@ -0,0 +1,41 @@
+use alloc::boxed::Box;
+
+/// Curry function of two arguments.
+///
+/// Takes function `f` and returns `|a: A| |b: B| f(a, b)`.
+///
+/// The inner function may be called many times, so it needs its own copy of
+/// `a` (and of `f`) for every call, hence the `A: Clone` and `F: Clone` bounds.
+///
+/// Stable rust can't express `impl Fn(A) -> impl Fn(B) -> R`, so the inner
+/// function is boxed.
+///
+/// **NOTE**: this function is under `#[cfg(feature = "alloc")]`
+///
+/// # Examples
+/// ```
+/// use fntools::curry2;
+///
+/// let add = curry2(i32::wrapping_add);
+/// let add_2 = add(2);
+///
+/// assert_eq!(curry2(i32::wrapping_add)(2)(3), 5);
+/// assert_eq!(add_2(40), 42);
+/// assert_eq!(add_2(-2), 0);
+/// ```
+///
+/// See also:
+/// - variadic nightly version of this function: [`unstable::curry`]
+///
+/// [`unstable::curry`]: crate::unstable::curry
+#[inline]
+pub fn curry2<'a, A, B, R, F>(f: F) -> impl Fn(A) -> Box<dyn Fn(B) -> R + 'a>
+where
+    A: Clone + 'a,
+    F: Fn(A, B) -> R + Clone + 'a,
+{
+    move |a: A| {
+        let f = f.clone();
+        Box::new(move |b: B| f(a.clone(), b))
+    }
+}
@@ -0,0 +1,41 @@
+/// Call a function only on every `n`-th call.
+///
+/// Takes function `f` and number `n` and returns function that counts its
+/// calls and calls `f` (returning `Some(output)`) only on the `n`-th,
+/// `2n`-th, ... calls, returning `None` without calling `f` otherwise.
+///
+/// This is useful for rate-limiting side effects (e.g. progress reporting)
+/// inside of a processing pipeline without timers.
+///
+/// `n == 0` is the same as `n == 1`, i.e. `f` is called every time.
+///
+/// # Examples
+/// ```
+/// use fntools::every_nth;
+///
+/// let mut fun = every_nth(|x: i32| x * 10, 3);
+///
+/// assert_eq!(fun(1), None);
+/// assert_eq!(fun(2), None);
+/// assert_eq!(fun(3), Some(30));
+/// assert_eq!(fun(4), None);
+/// assert_eq!(fun(5), None);
+/// assert_eq!(fun(6), Some(60));
+/// ```
+#[inline]
+pub fn every_nth<A, R, F>(mut f: F, n: usize) -> impl FnMut(A) -> Option<R>
+where
+    F: FnMut(A) -> R,
+{
+    let n = n.max(1);
+    let mut calls = 0;
+    move |a: A| {
+        calls += 1;
+        if calls == n {
+            calls = 0;
+            Some(f(a))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/// Turn a predicate into a function returning `Option`.
+///
+/// Takes predicate `p` and returns `|a: A| if p(&a) { Some(a) } else { None }`.
+///
+/// Unlike [`ValueExt::take_if`] this is a reusable function, so it can be
+/// used as a stage of a pipeline of `Option`-returning functions.
+///
+/// # Examples
+/// ```
+/// use fntools::{chain, guard};
+///
+/// let positive = guard(|x: &i32| *x > 0);
+///
+/// assert_eq!(positive(3), Some(3));
+/// assert_eq!(positive(-3), None);
+///
+/// let inverse = |x: i32| 100i32.checked_div(x);
+/// let fun = chain(guard(|x: &i32| *x > 0), move |x: Option<i32>| {
+///     x.and_then(inverse)
+/// });
+///
+/// assert_eq!(fun(4), Some(25));
+/// assert_eq!(fun(-4), None);
+/// assert_eq!(fun(0), None);
+/// ```
+///
+/// [`ValueExt::take_if`]: crate::value::ValueExt::take_if
+#[inline]
+pub fn guard<A, P>(p: P) -> impl Fn(A) -> Option<A>
+where
+    P: Fn(&A) -> bool,
+{
+    move |a: A| if p(&a) { Some(a) } else { None }
+}
+
+/// Turn a predicate which can be called only by unique reference into a
+/// function returning `Option`.
+///
+/// See [guard](self::guard) for documentation.
+#[inline]
+pub fn guard_mut<A, P>(mut p: P) -> impl FnMut(A) -> Option<A>
+where
+    P: FnMut(&A) -> bool,
+{
+    move |a: A| if p(&a) { Some(a) } else { None }
+}
@@ -0,0 +1,33 @@
+/// Repeat function `n` times.
+///
+/// Takes function `f` and returns `|a: A| f(f(...f(a)...))` where `f` is
+/// applied exactly `n` times. `iterate(f, 0)` is the identity function.
+///
+/// # Examples
+/// ```
+/// use fntools::iterate;
+///
+/// let times_8 = iterate(|x| x * 2, 3);
+/// assert_eq!(times_8(1), 8);
+///
+/// let id = iterate(|x| x * 2, 0);
+/// assert_eq!(id(1), 1);
+/// ```
+#[inline]
+pub fn iterate<A, F>(f: F, n: usize) -> impl Fn(A) -> A
+where
+    F: Fn(A) -> A,
+{
+    move |a: A| (0..n).fold(a, |acc, _| f(acc))
+}
+
+/// Repeat function (which can be called only by unique reference) `n` times.
+///
+/// See [iterate](self::iterate) for documentation.
+#[inline]
+pub fn iterate_mut<A, F>(mut f: F, n: usize) -> impl FnMut(A) -> A
+where
+    F: FnMut(A) -> A,
+{
+    move |a: A| (0..n).fold(a, |acc, _| f(acc))
+}
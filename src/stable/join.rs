@@ -0,0 +1,56 @@
+/// Feed the same argument to both parameters of a function.
+///
+/// Takes function `f` and returns `|a: A| f(a.clone(), a)` (this is `join`
+/// for functions in haskell's `Control.Monad`).
+///
+/// # Examples
+/// ```
+/// use fntools::join;
+///
+/// let square = join(i32::wrapping_mul);
+///
+/// assert_eq!(square(7), 49);
+/// assert_eq!(square(-3), 9);
+/// ```
+///
+/// ```
+/// use fntools::join;
+///
+/// let double = join(|a: String, b: String| a + &b);
+///
+/// assert_eq!(double(String::from("ab")), "abab");
+/// ```
+#[inline]
+pub fn join<A, R, F>(f: F) -> impl Fn(A) -> R
+where
+    A: Clone,
+    F: Fn(A, A) -> R,
+{
+    move |a: A| f(a.clone(), a)
+}
+
+/// Feed the same argument to both parameters of a function which can be
+/// called only once.
+///
+/// See [join](self::join) for documentation.
+#[inline]
+pub fn join_once<A, R, F>(f: F) -> impl FnOnce(A) -> R
+where
+    A: Clone,
+    F: FnOnce(A, A) -> R,
+{
+    move |a: A| f(a.clone(), a)
+}
+
+/// Feed the same argument to both parameters of a function which can be
+/// called only by unique reference.
+///
+/// See [join](self::join) for documentation.
+#[inline]
+pub fn join_mut<A, R, F>(mut f: F) -> impl FnMut(A) -> R
+where
+    A: Clone,
+    F: FnMut(A, A) -> R,
+{
+    move |a: A| f(a.clone(), a)
+}
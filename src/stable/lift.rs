@@ -0,0 +1,109 @@
+/// Lift a function over `Option`.
+///
+/// Takes function `f` and returns `|a: Option<A>| a.map(f)`, so `Some` values
+/// are transformed by `f` and `None` is passed through.
+///
+/// This lets ordinary functions be used as stages of a pipeline of
+/// `Option`-returning functions.
+///
+/// # Examples
+/// ```
+/// use fntools::{chain, lift_option};
+///
+/// let inc = lift_option(|x: i32| x + 1);
+///
+/// assert_eq!(inc(Some(1)), Some(2));
+/// assert_eq!(inc(None), None);
+///
+/// let first_char = chain(|s: &str| s.chars().next(), lift_option(char::is_uppercase));
+///
+/// assert_eq!(first_char("Hello"), Some(true));
+/// assert_eq!(first_char(""), None);
+/// ```
+///
+/// See also:
+/// - [`lift_result`]
+///
+/// [`lift_result`]: crate::lift_result
+#[inline]
+pub fn lift_option<A, B, F>(f: F) -> impl Fn(Option<A>) -> Option<B>
+where
+    F: Fn(A) -> B,
+{
+    move |a: Option<A>| a.map(&f)
+}
+
+/// Lift a function which can be called only once over `Option`.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option_once<A, B, F>(f: F) -> impl FnOnce(Option<A>) -> Option<B>
+where
+    F: FnOnce(A) -> B,
+{
+    move |a: Option<A>| a.map(f)
+}
+
+/// Lift a function which can be called only by unique reference over
+/// `Option`.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option_mut<A, B, F>(mut f: F) -> impl FnMut(Option<A>) -> Option<B>
+where
+    F: FnMut(A) -> B,
+{
+    move |a: Option<A>| a.map(&mut f)
+}
+
+/// Lift a function over `Result`.
+///
+/// Takes function `f` and returns `|a: Result<A, E>| a.map(f)`, so `Ok`
+/// values are transformed by `f` and `Err` is passed through.
+///
+/// # Examples
+/// ```
+/// use fntools::lift_result;
+///
+/// let inc = lift_result(|x: i32| x + 1);
+///
+/// assert_eq!(inc(Ok::<_, &str>(1)), Ok(2));
+/// assert_eq!(inc(Err("error")), Err("error"));
+/// ```
+///
+/// See also:
+/// - [`lift_option`]
+/// - [`fntools::map_err`], which transforms `Err` values instead
+///
+/// [`lift_option`]: crate::lift_option
+/// [`fntools::map_err`]: crate::map_err
+#[inline]
+pub fn lift_result<A, B, E, F>(f: F) -> impl Fn(Result<A, E>) -> Result<B, E>
+where
+    F: Fn(A) -> B,
+{
+    move |a: Result<A, E>| a.map(&f)
+}
+
+/// Lift a function which can be called only once over `Result`.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+pub fn lift_result_once<A, B, E, F>(f: F) -> impl FnOnce(Result<A, E>) -> Result<B, E>
+where
+    F: FnOnce(A) -> B,
+{
+    move |a: Result<A, E>| a.map(f)
+}
+
+/// Lift a function which can be called only by unique reference over
+/// `Result`.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+pub fn lift_result_mut<A, B, E, F>(mut f: F) -> impl FnMut(Result<A, E>) -> Result<B, E>
+where
+    F: FnMut(A) -> B,
+{
+    move |a: Result<A, E>| a.map(&mut f)
+}
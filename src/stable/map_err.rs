@@ -0,0 +1,61 @@
+/// Map error of a fallible function.
+///
+/// Takes functions `f` and `m` and returns `|a: A| f(a).map_err(m)`, so `Ok`
+/// values are left untouched and `Err` values are transformed by `m`.
+///
+/// This is useful when chaining fallible functions whose error types differ.
+///
+/// # Examples
+/// ```
+/// use fntools::map_err;
+/// use std::num::ParseIntError;
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let parse = map_err(parse, |e: ParseIntError| format!("invalid number: {}", e));
+///
+/// assert_eq!(parse("42"), Ok(42));
+/// assert_eq!(
+///     parse("forty two"),
+///     Err(String::from(
+///         "invalid number: invalid digit found in string"
+///     ))
+/// );
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::map_err`]
+///
+/// [`FnExt::map_err`]: crate::unstable::FnExt::map_err
+#[inline]
+pub fn map_err<A, T, E, E2, F, M>(f: F, m: M) -> impl Fn(A) -> Result<T, E2>
+where
+    F: Fn(A) -> Result<T, E>,
+    M: Fn(E) -> E2,
+{
+    move |a: A| f(a).map_err(&m)
+}
+
+/// Map error of a fallible function which can be called only once.
+///
+/// See [map_err](self::map_err) for documentation.
+#[inline]
+pub fn map_err_once<A, T, E, E2, F, M>(f: F, m: M) -> impl FnOnce(A) -> Result<T, E2>
+where
+    F: FnOnce(A) -> Result<T, E>,
+    M: FnOnce(E) -> E2,
+{
+    move |a: A| f(a).map_err(m)
+}
+
+/// Map error of a fallible function which can be called only by unique
+/// reference.
+///
+/// See [map_err](self::map_err) for documentation.
+#[inline]
+pub fn map_err_mut<A, T, E, E2, F, M>(mut f: F, mut m: M) -> impl FnMut(A) -> Result<T, E2>
+where
+    F: FnMut(A) -> Result<T, E>,
+    M: FnMut(E) -> E2,
+{
+    move |a: A| f(a).map_err(&mut m)
+}
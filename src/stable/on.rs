@@ -0,0 +1,68 @@
+/// Combine two values after projecting them with the same function.
+///
+/// Takes functions `f` and `g` and returns `|x: A, y: A| f(g(x), g(y))` (like
+/// [`on`] from haskell's `Data.Function`).
+///
+/// # Examples
+/// ```
+/// use fntools::on;
+/// use std::cmp::Ordering;
+///
+/// let by_len = on(|a: usize, b: usize| a.cmp(&b), str::len);
+///
+/// assert_eq!(by_len("hello", "hi"), Ordering::Greater);
+/// assert_eq!(by_len("hi", "ok"), Ordering::Equal);
+/// ```
+///
+/// Comparing structs by a field:
+/// ```
+/// use fntools::on;
+/// use std::cmp::Ordering;
+///
+/// struct Person {
+///     name: &'static str,
+///     age: u8,
+/// }
+///
+/// let alice = Person {
+///     name: "Alice",
+///     age: 31,
+/// };
+/// let bob = Person {
+///     name: "Bob",
+///     age: 17,
+/// };
+///
+/// let by_age = on(|a: u8, b: u8| a.cmp(&b), |p: &Person| p.age);
+/// assert_eq!(by_age(&alice, &bob), Ordering::Greater);
+///
+/// let by_name = on(|a: &str, b: &str| a.cmp(b), |p: &Person| p.name);
+/// assert_eq!(by_name(&alice, &bob), Ordering::Less);
+/// ```
+///
+/// [`on`]: https://hackage.haskell.org/package/base/docs/Data-Function.html#v:on
+#[inline]
+pub fn on<A, B, R, F, G>(f: F, g: G) -> impl Fn(A, A) -> R
+where
+    F: Fn(B, B) -> R,
+    G: Fn(A) -> B,
+{
+    move |x: A, y: A| f(g(x), g(y))
+}
+
+/// Combine two values after projecting them with the same function which can
+/// be called only by unique reference.
+///
+/// See [on](self::on) for documentation.
+#[inline]
+pub fn on_mut<A, B, R, F, G>(mut f: F, mut g: G) -> impl FnMut(A, A) -> R
+where
+    F: FnMut(B, B) -> R,
+    G: FnMut(A) -> B,
+{
+    move |x: A, y: A| {
+        let x = g(x);
+        let y = g(y);
+        f(x, y)
+    }
+}
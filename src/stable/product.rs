@@ -44,3 +44,130 @@ where
 {
     move |a: A, x: X| (f(a), g(x))
 }
+
+/// Cartesian product of three functions.
+///
+/// Takes functions `f`, `g` and `h` and returns
+/// `|a: A, x: X, w: W| (f(a), g(x), h(w))`.
+///
+/// This is the same as nesting [`product`](self::product)s, but the result is
+/// a flat tuple.
+///
+/// ## Example
+/// ```
+/// use fntools::product3;
+///
+/// let fun = product3(str::len, |x: i32| x * 2, char::is_alphabetic);
+///
+/// assert_eq!(fun("four", 21, 'x'), (4, 42, true));
+/// ```
+#[inline]
+pub fn product3<A, B, X, Y, W, Z, F, G, H>(f: F, g: G, h: H) -> impl Fn(A, X, W) -> (B, Y, Z)
+where
+    F: Fn(A) -> B,
+    G: Fn(X) -> Y,
+    H: Fn(W) -> Z,
+{
+    move |a: A, x: X, w: W| (f(a), g(x), h(w))
+}
+
+/// Cartesian product of three functions which can be called only once.
+///
+/// See [product3](self::product3) for documentation.
+#[inline]
+pub fn product3_once<A, B, X, Y, W, Z, F, G, H>(
+    f: F,
+    g: G,
+    h: H,
+) -> impl FnOnce(A, X, W) -> (B, Y, Z)
+where
+    F: FnOnce(A) -> B,
+    G: FnOnce(X) -> Y,
+    H: FnOnce(W) -> Z,
+{
+    move |a: A, x: X, w: W| (f(a), g(x), h(w))
+}
+
+/// Cartesian product of three functions which can be called only by unique
+/// reference.
+///
+/// See [product3](self::product3) for documentation.
+#[inline]
+pub fn product3_mut<A, B, X, Y, W, Z, F, G, H>(
+    mut f: F,
+    mut g: G,
+    mut h: H,
+) -> impl FnMut(A, X, W) -> (B, Y, Z)
+where
+    F: FnMut(A) -> B,
+    G: FnMut(X) -> Y,
+    H: FnMut(W) -> Z,
+{
+    move |a: A, x: X, w: W| (f(a), g(x), h(w))
+}
+
+/// Cartesian product of functions taking a pair.
+///
+/// Takes functions `f` and `g` and returns `|(a, x): (A, X)| (f(a), g(x))`
+/// (this is `***` from Haskell's `Control.Arrow`).
+///
+/// Unlike [`product`](self::product) which takes two arguments, the result
+/// takes a single tuple, so it can be chained after functions returning pairs
+/// or used with iterators over pairs.
+///
+/// ## Examples
+/// ```
+/// use fntools::split;
+///
+/// let pairs = vec![("a", 1), ("bb", 2)];
+/// let res: Vec<_> = pairs
+///     .into_iter()
+///     .map(split(str::len, |x: i32| x * 10))
+///     .collect();
+///
+/// assert_eq!(res, [(1, 10), (2, 20)]);
+/// ```
+///
+/// Elements can be cross-wired by swapping the pair first:
+/// ```
+/// use fntools::{chain, split};
+///
+/// let swap = |(a, x): (i32, char)| (x, a);
+/// let fun = chain(swap, split(char::is_alphabetic, |a: i32| a + 1));
+///
+/// assert_eq!(fun((1, 'x')), (true, 2));
+/// ```
+#[inline]
+pub fn split<A, B, X, Y, F, G>(f: F, g: G) -> impl Fn((A, X)) -> (B, Y)
+where
+    F: Fn(A) -> B,
+    G: Fn(X) -> Y,
+{
+    move |(a, x): (A, X)| (f(a), g(x))
+}
+
+/// Cartesian product of functions taking a pair which can be called only
+/// once.
+///
+/// See [split](self::split) for documentation.
+#[inline]
+pub fn split_once<A, B, X, Y, F, G>(f: F, g: G) -> impl FnOnce((A, X)) -> (B, Y)
+where
+    F: FnOnce(A) -> B,
+    G: FnOnce(X) -> Y,
+{
+    move |(a, x): (A, X)| (f(a), g(x))
+}
+
+/// Cartesian product of functions taking a pair which can be called only by
+/// unique reference.
+///
+/// See [split](self::split) for documentation.
+#[inline]
+pub fn split_mut<A, B, X, Y, F, G>(mut f: F, mut g: G) -> impl FnMut((A, X)) -> (B, Y)
+where
+    F: FnMut(A) -> B,
+    G: FnMut(X) -> Y,
+{
+    move |(a, x): (A, X)| (f(a), g(x))
+}
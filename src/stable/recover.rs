@@ -0,0 +1,58 @@
+/// Recover from errors of a fallible function.
+///
+/// Takes functions `f` and `fallback` and returns `|a: A|
+/// f(a).unwrap_or_else(fallback)`, so `Ok` values are unwrapped and `Err`
+/// values are turned into values by `fallback`.
+///
+/// This makes a fallible function total, e.g. to terminate a pipeline with a
+/// default.
+///
+/// # Examples
+/// ```
+/// use fntools::recover;
+/// use std::num::ParseIntError;
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let parse = recover(parse, |_: ParseIntError| 0);
+///
+/// assert_eq!(parse("42"), 42);
+/// assert_eq!(parse("forty two"), 0);
+/// ```
+///
+/// See also:
+/// - [`fntools::map_err`]
+///
+/// [`fntools::map_err`]: crate::map_err
+#[inline]
+pub fn recover<A, T, E, F, R>(f: F, fallback: R) -> impl Fn(A) -> T
+where
+    F: Fn(A) -> Result<T, E>,
+    R: Fn(E) -> T,
+{
+    move |a: A| f(a).unwrap_or_else(&fallback)
+}
+
+/// Recover from errors of a fallible function which can be called only once.
+///
+/// See [recover](self::recover) for documentation.
+#[inline]
+pub fn recover_once<A, T, E, F, R>(f: F, fallback: R) -> impl FnOnce(A) -> T
+where
+    F: FnOnce(A) -> Result<T, E>,
+    R: FnOnce(E) -> T,
+{
+    move |a: A| f(a).unwrap_or_else(fallback)
+}
+
+/// Recover from errors of a fallible function which can be called only by
+/// unique reference.
+///
+/// See [recover](self::recover) for documentation.
+#[inline]
+pub fn recover_mut<A, T, E, F, R>(mut f: F, mut fallback: R) -> impl FnMut(A) -> T
+where
+    F: FnMut(A) -> Result<T, E>,
+    R: FnMut(E) -> T,
+{
+    move |a: A| f(a).unwrap_or_else(&mut fallback)
+}
@@ -0,0 +1,43 @@
+use alloc::vec::Vec;
+
+use crate::BoxFn;
+
+/// Dispatch to one of the branch functions.
+///
+/// Takes function `router` and list of functions `branches` and returns
+/// function that calls `router` with a reference to the argument and then
+/// calls the branch with the returned index (in other words
+/// `|a: A| branches[router(&a)](a)`).
+///
+/// The number of branches may be determined at runtime, but all of them must
+/// have the same type `A -> R`. To dispatch on a `bool` or an enum, convert it
+/// to an index (e.g. `b as usize`).
+///
+/// **NOTE**: this function is under `#[cfg(feature = "alloc")]`
+///
+/// # Panics
+///
+/// The returned function panics if `router` returns an index out of range
+/// of `branches` (the index is not clamped).
+///
+/// # Examples
+/// ```
+/// use fntools::{boxfn, select};
+///
+/// let parity = select(
+///     |x: &u32| (x % 2) as usize,
+///     vec![boxfn(|x: u32| x / 2), boxfn(|x: u32| 3 * x + 1)],
+/// );
+///
+/// assert_eq!(parity(10), 5);
+/// assert_eq!(parity(5), 16);
+/// ```
+#[inline]
+pub fn select<'a, A, R, S>(router: S, branches: Vec<BoxFn<'a, A, R>>) -> impl Fn(A) -> R + 'a
+where
+    A: 'a,
+    R: 'a,
+    S: Fn(&A) -> usize + 'a,
+{
+    move |a: A| branches[router(&a)](a)
+}
@@ -0,0 +1,62 @@
+/// Supply first argument to the function of two arguments.
+///
+/// Takes function `f` and argument `a` and returns `|b: B| f(a.clone(), b)`.
+///
+/// # Examples
+/// ```
+/// use fntools::supply_2;
+///
+/// let greet = supply_2(
+///     |greeting: &str, name: &str| format!("{}, {}!", greeting, name),
+///     "Hello",
+/// );
+///
+/// assert_eq!(greet("Alice"), "Hello, Alice!");
+/// assert_eq!(greet("Bob"), "Hello, Bob!");
+/// ```
+///
+/// See also:
+/// - [`supply_3`](crate::supply_3)
+/// - for functions of any number of arguments (requires nightly):
+///   [`FnExt::supply`]
+///
+/// [`FnExt::supply`]: crate::unstable::FnExt::supply
+#[inline]
+pub fn supply_2<A, B, R, F>(f: F, a: A) -> impl Fn(B) -> R
+where
+    A: Clone,
+    F: Fn(A, B) -> R,
+{
+    move |b: B| f(a.clone(), b)
+}
+
+/// Supply first argument to the function of three arguments.
+///
+/// Takes function `f` and argument `a` and returns
+/// `|b: B, c: C| f(a.clone(), b, c)`.
+///
+/// # Examples
+/// ```
+/// use fntools::supply_3;
+///
+/// let clamp = |x: i32, min: i32, max: i32| x.max(min).min(max);
+/// let clamp_7 = supply_3(clamp, 7);
+///
+/// assert_eq!(clamp_7(0, 5), 5);
+/// assert_eq!(clamp_7(8, 10), 8);
+/// ```
+///
+/// See also:
+/// - [`supply_2`](crate::supply_2)
+/// - for functions of any number of arguments (requires nightly):
+///   [`FnExt::supply`]
+///
+/// [`FnExt::supply`]: crate::unstable::FnExt::supply
+#[inline]
+pub fn supply_3<A, B, C, R, F>(f: F, a: A) -> impl Fn(B, C) -> R
+where
+    A: Clone,
+    F: Fn(A, B, C) -> R,
+{
+    move |b: B, c: C| f(a.clone(), b, c)
+}
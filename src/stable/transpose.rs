@@ -0,0 +1,116 @@
+/// Transpose output of a function returning `Result<Option<T>, E>`.
+///
+/// Takes function `f` and returns `|a: A| f(a).transpose()`, so the output
+/// is `Option<Result<T, E>>` instead.
+///
+/// This is useful when an optional stage of a pipeline is followed by
+/// a fallible one or vice versa.
+///
+/// # Examples
+/// ```
+/// use fntools::transpose_result;
+/// use std::num::ParseIntError;
+///
+/// // empty string means "no value"
+/// let parse = |s: &str| {
+///     if s.is_empty() {
+///         Ok(None)
+///     } else {
+///         s.parse::<i32>().map(Some)
+///     }
+/// };
+/// let parse = transpose_result(parse);
+///
+/// assert_eq!(parse("42"), Some(Ok(42)));
+/// assert_eq!(parse(""), None);
+/// assert!(matches!(parse("x"), Some(Err::<_, ParseIntError>(_))));
+/// ```
+///
+/// See also:
+/// - the other direction: [`transpose_option`]
+///
+/// [`transpose_option`]: crate::transpose_option
+#[inline]
+pub fn transpose_result<A, T, E, F>(f: F) -> impl Fn(A) -> Option<Result<T, E>>
+where
+    F: Fn(A) -> Result<Option<T>, E>,
+{
+    move |a: A| f(a).transpose()
+}
+
+/// Transpose output of a function returning `Result<Option<T>, E>` which can
+/// be called only once.
+///
+/// See [transpose_result](self::transpose_result) for documentation.
+#[inline]
+pub fn transpose_result_once<A, T, E, F>(f: F) -> impl FnOnce(A) -> Option<Result<T, E>>
+where
+    F: FnOnce(A) -> Result<Option<T>, E>,
+{
+    move |a: A| f(a).transpose()
+}
+
+/// Transpose output of a function returning `Result<Option<T>, E>` which can
+/// be called only by unique reference.
+///
+/// See [transpose_result](self::transpose_result) for documentation.
+#[inline]
+pub fn transpose_result_mut<A, T, E, F>(mut f: F) -> impl FnMut(A) -> Option<Result<T, E>>
+where
+    F: FnMut(A) -> Result<Option<T>, E>,
+{
+    move |a: A| f(a).transpose()
+}
+
+/// Transpose output of a function returning `Option<Result<T, E>>`.
+///
+/// Takes function `f` and returns `|a: A| f(a).transpose()`, so the output
+/// is `Result<Option<T>, E>` instead.
+///
+/// # Examples
+/// ```
+/// use fntools::transpose_option;
+///
+/// let first = |v: &[&str]| v.first().map(|s| s.parse::<u8>());
+/// let first = transpose_option(first);
+///
+/// assert_eq!(first(&["1", "2"]), Ok(Some(1)));
+/// assert_eq!(first(&[]), Ok(None));
+/// assert!(first(&["256"]).is_err());
+/// ```
+///
+/// See also:
+/// - the other direction: [`transpose_result`]
+///
+/// [`transpose_result`]: crate::transpose_result
+#[inline]
+pub fn transpose_option<A, T, E, F>(f: F) -> impl Fn(A) -> Result<Option<T>, E>
+where
+    F: Fn(A) -> Option<Result<T, E>>,
+{
+    move |a: A| f(a).transpose()
+}
+
+/// Transpose output of a function returning `Option<Result<T, E>>` which can
+/// be called only once.
+///
+/// See [transpose_option](self::transpose_option) for documentation.
+#[inline]
+pub fn transpose_option_once<A, T, E, F>(f: F) -> impl FnOnce(A) -> Result<Option<T>, E>
+where
+    F: FnOnce(A) -> Option<Result<T, E>>,
+{
+    move |a: A| f(a).transpose()
+}
+
+/// Transpose output of a function returning `Option<Result<T, E>>` which can
+/// be called only by unique reference.
+///
+/// See [transpose_option](self::transpose_option) for documentation.
+#[inline]
+pub fn transpose_option_mut<A, T, E, F>(mut f: F) -> impl FnMut(A) -> Result<Option<T>, E>
+where
+    F: FnMut(A) -> Option<Result<T, E>>,
+{
+    move |a: A| f(a).transpose()
+}
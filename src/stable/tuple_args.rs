@@ -0,0 +1,60 @@
+/// Turn function of two arguments into function of one argument — tuple of
+/// two elements.
+///
+/// Takes function `f` and returns `|(a, b): (A, B)| f(a, b)`. This makes the
+/// common `.map(|(k, v)| ...)` pattern point-free.
+///
+/// # Examples
+/// ```
+/// use fntools::tuple_args_2;
+///
+/// let add = |a: i32, b: i32| a + b;
+/// let sums: Vec<_> = vec![(1, 2), (3, 4)]
+///     .into_iter()
+///     .map(tuple_args_2(add))
+///     .collect();
+///
+/// assert_eq!(sums, [3, 7]);
+/// ```
+///
+/// See also:
+/// - [`tuple_args_3`](crate::tuple_args_3)
+/// - for functions of any number of arguments (requires nightly):
+///   [`unstable::untuple`]
+///
+/// [`unstable::untuple`]: crate::unstable::untuple
+#[inline]
+pub fn tuple_args_2<A, B, R, F>(f: F) -> impl Fn((A, B)) -> R
+where
+    F: Fn(A, B) -> R,
+{
+    move |(a, b): (A, B)| f(a, b)
+}
+
+/// Turn function of three arguments into function of one argument — tuple of
+/// three elements.
+///
+/// Takes function `f` and returns `|(a, b, c): (A, B, C)| f(a, b, c)`.
+///
+/// # Examples
+/// ```
+/// use fntools::tuple_args_3;
+///
+/// let volume = tuple_args_3(|x: u32, y: u32, z: u32| x * y * z);
+///
+/// assert_eq!(volume((2, 3, 4)), 24);
+/// ```
+///
+/// See also:
+/// - [`tuple_args_2`](crate::tuple_args_2)
+/// - for functions of any number of arguments (requires nightly):
+///   [`unstable::untuple`]
+///
+/// [`unstable::untuple`]: crate::unstable::untuple
+#[inline]
+pub fn tuple_args_3<A, B, C, R, F>(f: F) -> impl Fn((A, B, C)) -> R
+where
+    F: Fn(A, B, C) -> R,
+{
+    move |(a, b, c): (A, B, C)| f(a, b, c)
+}
@@ -37,3 +37,72 @@ where
 {
     move |a: A| drop(f(a))
 }
+
+/// Replace function output with a constant.
+///
+/// Takes function `f` and `value` and returns function that calls `f` (for
+/// its side effects), drops its output and returns a clone of `value`. This
+/// is a generalization of [`unit`](unit()) (which always returns `()`), useful
+/// e.g. as a pipeline terminator that reports a fixed status regardless of the
+/// inner result.
+///
+/// # Example
+/// ```
+/// use fntools::{const_with, unit};
+/// use std::cell::RefCell;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Status {
+///     Done,
+/// }
+///
+/// let log = RefCell::new(Vec::new());
+/// let record = |msg: &str| log.borrow_mut().push(msg.len());
+///
+/// // `unit` returns `()`, `const_with` returns the given value
+/// assert_eq!(unit(record)("hi"), ());
+/// assert_eq!(const_with(record, Status::Done)("hello"), Status::Done);
+/// assert_eq!(*log.borrow(), [2, 5]);
+/// ```
+#[inline]
+pub fn const_with<A, B, T, F>(f: F, value: T) -> impl Fn(A) -> T
+where
+    F: Fn(A) -> B,
+    T: Clone,
+{
+    move |a: A| {
+        drop(f(a));
+        value.clone()
+    }
+}
+
+/// Replace output of a function which can be called only once with a
+/// constant.
+///
+/// See [const_with](self::const_with) for documentation.
+#[inline]
+pub fn const_with_once<A, B, T, F>(f: F, value: T) -> impl FnOnce(A) -> T
+where
+    F: FnOnce(A) -> B,
+{
+    move |a: A| {
+        drop(f(a));
+        value
+    }
+}
+
+/// Replace output of a function which can be called only by unique
+/// reference with a constant.
+///
+/// See [const_with](self::const_with) for documentation.
+#[inline]
+pub fn const_with_mut<A, B, T, F>(mut f: F, value: T) -> impl FnMut(A) -> T
+where
+    F: FnMut(A) -> B,
+    T: Clone,
+{
+    move |a: A| {
+        drop(f(a));
+        value.clone()
+    }
+}
@@ -1,4 +1,16 @@
-use std::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    string::String,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    hash::BuildHasher,
+};
 
 /// Represents a type which can have functions applied to it (implemented
 /// by default for all types).
@@ -240,3 +252,313 @@ pub trait Also: Sized {
 impl<T> Also for T {
     // use default definitions...
 }
+
+/// Extensions for all types which don't fit into [`Apply`] or [`Also`]
+/// (implemented by default for all types).
+pub trait ValueExt: Sized {
+    /// Collect `self` into a collection.
+    ///
+    /// i.e. `C::default()` extended with `self`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set: BTreeSet<_> = vec![3, 1, 2, 1].build();
+    /// assert_eq!(set.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn build<C>(self) -> C
+    where
+        Self: IntoIterator,
+        C: Default + Extend<Self::Item>,
+    {
+        let mut collection = C::default();
+        collection.extend(self);
+        collection
+    }
+
+    /// Collect `self` into a collection pre-sized from the size hint of the
+    /// iterator.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "alloc")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// let vec: Vec<_> = (0..5).map(|x| x * x).build_with_capacity();
+    ///
+    /// assert_eq!(vec, [0, 1, 4, 9, 16]);
+    /// assert!(vec.capacity() >= 5);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn build_with_capacity<C>(self) -> C
+    where
+        Self: IntoIterator,
+        C: WithCapacity + Extend<Self::Item>,
+    {
+        let iter = self.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut collection = C::with_capacity(lower);
+        collection.extend(iter);
+        collection
+    }
+
+    /// Split `self` into elements that satisfy the predicate and those that
+    /// don't.
+    ///
+    /// i.e. `self.into_iter().partition(f)`
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "alloc")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// let (evens, odds) = vec![1, 2, 3, 4].partition_by(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(evens, [2, 4]);
+    /// assert_eq!(odds, [1, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn partition_by<F>(self, f: F) -> (Vec<Self::Item>, Vec<Self::Item>)
+    where
+        Self: IntoIterator,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.into_iter().partition(f)
+    }
+
+    /// Build `Vec` of `n` elements produced by `f` from a reference to
+    /// `self`.
+    ///
+    /// i.e. `(0..n).map(|_| f(&self)).collect()`, `f` is called exactly `n`
+    /// times.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "alloc")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// let empty: Vec<String> = ().repeat_with(3, |_| String::default());
+    /// assert_eq!(empty, ["", "", ""]);
+    ///
+    /// let mut next_id = 0;
+    /// let names = "user".repeat_with(3, |prefix| {
+    ///     next_id += 1;
+    ///     format!("{}{}", prefix, next_id)
+    /// });
+    /// assert_eq!(names, ["user1", "user2", "user3"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn repeat_with<R, F>(&self, n: usize, mut f: F) -> Vec<R>
+    where
+        F: FnMut(&Self) -> R,
+    {
+        (0..n).map(|_| f(self)).collect()
+    }
+
+    /// Build an error from `self` and a context.
+    ///
+    /// i.e. `E::from((self, ctx))`. This is useful for attaching the failing
+    /// value to an error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct OutOfRange {
+    ///     value: i32,
+    ///     reason: &'static str,
+    /// }
+    ///
+    /// impl From<(i32, &'static str)> for OutOfRange {
+    ///     fn from((value, reason): (i32, &'static str)) -> Self { OutOfRange { value, reason } }
+    /// }
+    ///
+    /// fn percent(x: i32) -> Result<u8, OutOfRange> {
+    ///     if (0..=100).contains(&x) {
+    ///         Ok(x as u8)
+    ///     } else {
+    ///         Err(x.context_err("percent must be in 0..=100"))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(percent(42), Ok(42));
+    /// assert_eq!(
+    ///     percent(142),
+    ///     Err(OutOfRange {
+    ///         value: 142,
+    ///         reason: "percent must be in 0..=100"
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    fn context_err<C, E>(self, ctx: C) -> E
+    where
+        E: From<(Self, C)>,
+    {
+        E::from((self, ctx))
+    }
+
+    /// Returns `Some(self)` if the predicate holds and `None` otherwise.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// assert_eq!(17.take_if(|x| *x > 0), Some(17));
+    /// assert_eq!((-17).take_if(|x| *x > 0), None);
+    /// ```
+    #[inline]
+    fn take_if<P>(self, p: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        if p(&self) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `Some(self)` if the predicate doesn't hold and `None`
+    /// otherwise.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// assert_eq!(17.take_unless(|x| *x > 0), None);
+    /// assert_eq!((-17).take_unless(|x| *x > 0), Some(-17));
+    /// ```
+    #[inline]
+    fn take_unless<P>(self, p: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        self.take_if(|this| !p(this))
+    }
+
+    /// Compute a value from a borrow of `self`, leaving `self` usable.
+    ///
+    /// i.e. literally `f(&self)`, same as [`Apply::apply_ref`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// let greeting = String::from("hello");
+    /// let len = greeting.pipe_ref(|s| s.len());
+    ///
+    /// assert_eq!(len, 5);
+    /// assert_eq!(greeting, "hello");
+    /// ```
+    #[inline]
+    fn pipe_ref<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&Self) -> R,
+    {
+        f(self)
+    }
+
+    /// Turn `self` into a function of no arguments returning clones of
+    /// `self`.
+    ///
+    /// This is useful as a default factory, see also [`constant`] module.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::value::ValueExt;
+    ///
+    /// let answer = 42.into_constant();
+    ///
+    /// assert_eq!(answer(), 42);
+    /// assert_eq!(answer(), 42);
+    /// ```
+    ///
+    /// [`constant`]: crate::constant
+    #[inline]
+    fn into_constant(self) -> impl Fn() -> Self
+    where
+        Self: Clone,
+    {
+        move || self.clone()
+    }
+}
+
+impl<T> ValueExt for T {
+    // use default definitions...
+}
+
+/// Collections which can be created with preallocated capacity.
+///
+/// **NOTE**: this trait is under `#[cfg(feature = "alloc")]`, impls for
+/// `HashMap` and `HashSet` are under `#[cfg(feature = "std")]`
+#[cfg(feature = "alloc")]
+pub trait WithCapacity {
+    /// Creates empty collection with space for at least `capacity` elements.
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl WithCapacity for String {
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self { String::with_capacity(capacity) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> WithCapacity for Vec<T> {
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self { Vec::with_capacity(capacity) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> WithCapacity for VecDeque<T> {
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self { VecDeque::with_capacity(capacity) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Ord> WithCapacity for BinaryHeap<T> {
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self { BinaryHeap::with_capacity(capacity) }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> WithCapacity for HashSet<T, S>
+where
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self {
+        HashSet::with_capacity_and_hasher(capacity, S::default())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> WithCapacity for HashMap<K, V, S>
+where
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self {
+        HashMap::with_capacity_and_hasher(capacity, S::default())
+    }
+}
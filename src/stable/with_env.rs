@@ -0,0 +1,68 @@
+/// Supply shared environment to a function.
+///
+/// Takes environment `env` and function `f` and returns
+/// `move |a: A| f(&env, a)` (this models the `Reader` monad: every stage of a
+/// pipeline reads the same environment without it being passed through the
+/// pipeline or stored in a global).
+///
+/// Unlike [`with_state`] the environment can't be changed, so the result is
+/// `Fn`.
+///
+/// # Examples
+/// ```
+/// use fntools::{chain, with_env};
+///
+/// #[derive(Clone)]
+/// struct Config {
+///     base: u32,
+///     prefix: &'static str,
+/// }
+///
+/// let config = Config {
+///     base: 16,
+///     prefix: "0x",
+/// };
+///
+/// let parse = with_env(config.clone(), |cfg: &Config, s: &str| {
+///     u32::from_str_radix(s, cfg.base).unwrap_or(0)
+/// });
+/// let show = with_env(config, |cfg: &Config, x: u32| {
+///     format!("{}{:x}", cfg.prefix, x + 1)
+/// });
+/// let next = chain(parse, show);
+///
+/// assert_eq!(next("ff"), "0x100");
+/// assert_eq!(next("9"), "0xa");
+/// ```
+///
+/// [`with_state`]: crate::with_state
+#[inline]
+pub fn with_env<E, A, R, F>(env: E, f: F) -> impl Fn(A) -> R
+where
+    F: Fn(&E, A) -> R,
+{
+    move |a: A| f(&env, a)
+}
+
+/// Supply shared environment to a function which can be called only once.
+///
+/// See [with_env](self::with_env) for documentation.
+#[inline]
+pub fn with_env_once<E, A, R, F>(env: E, f: F) -> impl FnOnce(A) -> R
+where
+    F: FnOnce(&E, A) -> R,
+{
+    move |a: A| f(&env, a)
+}
+
+/// Supply shared environment to a function which can be called only by
+/// unique reference.
+///
+/// See [with_env](self::with_env) for documentation.
+#[inline]
+pub fn with_env_mut<E, A, R, F>(env: E, mut f: F) -> impl FnMut(A) -> R
+where
+    F: FnMut(&E, A) -> R,
+{
+    move |a: A| f(&env, a)
+}
@@ -0,0 +1,43 @@
+/// Thread mutable state across calls of a function.
+///
+/// Takes initial state `init` and function `f` and returns
+/// `move |a: A| f(&mut state, a)` where `state` starts as `init` and is kept
+/// between calls (like the closure passed to [`Iterator::scan`]).
+///
+/// This turns a step function into a plain `FnMut(A) -> R` that can be passed
+/// to e.g. [`Iterator::map`] or used as an event handler.
+///
+/// # Examples
+/// ```
+/// use fntools::with_state;
+///
+/// let mut running_sum = with_state(0, |sum: &mut i32, x: i32| {
+///     *sum += x;
+///     *sum
+/// });
+///
+/// assert_eq!(running_sum(1), 1);
+/// assert_eq!(running_sum(2), 3);
+/// assert_eq!(running_sum(3), 6);
+/// ```
+///
+/// ```
+/// use fntools::with_state;
+///
+/// let numbered = with_state(1, |n: &mut usize, line: &str| {
+///     let res = format!("{}: {}", n, line);
+///     *n += 1;
+///     res
+/// });
+/// let lines: Vec<_> = "first\nsecond".lines().map(numbered).collect();
+///
+/// assert_eq!(lines, ["1: first", "2: second"]);
+/// ```
+#[inline]
+pub fn with_state<S, A, R, F>(init: S, mut f: F) -> impl FnMut(A) -> R
+where
+    F: FnMut(&mut S, A) -> R,
+{
+    let mut state = init;
+    move |a: A| f(&mut state, a)
+}
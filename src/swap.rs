@@ -0,0 +1,184 @@
+use std::{
+    fmt::{Debug, Error, Formatter},
+    mem,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+/// Function stage that can be replaced at runtime.
+///
+/// `SwappableStage` dispatches every call to the currently installed function.
+/// Clones share the installed function, so a pipeline can hold one clone while
+/// the other is used to [`swap`](SwappableStage::swap) the function (e.g. on a
+/// feature-flag change) without rebuilding the pipeline.
+///
+/// On stable use [`call`](SwappableStage::call), with `nightly` feature
+/// `SwappableStage` also implements `Fn(A) -> B`.
+///
+/// ## Examples
+/// ```
+/// use fntools::{chain, swap::SwappableStage};
+///
+/// let stage = SwappableStage::new(|x: i32| x + 1);
+/// let pipeline = {
+///     let stage = stage.clone();
+///     chain(|x: i32| x * 10, move |x| stage.call(x))
+/// };
+///
+/// assert_eq!(pipeline(4), 41);
+///
+/// stage.swap(|x: i32| x - 1);
+/// assert_eq!(pipeline(4), 39);
+/// ```
+pub struct SwappableStage<A, B> {
+    f: Arc<RwLock<Stage<A, B>>>,
+}
+
+type Stage<A, B> = Arc<dyn Fn(A) -> B + Send + Sync>;
+
+impl<A, B> SwappableStage<A, B> {
+    /// Creates stage with `f` installed.
+    #[inline]
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> B + Send + Sync + 'static,
+    {
+        SwappableStage {
+            f: Arc::new(RwLock::new(Arc::new(f))),
+        }
+    }
+
+    /// Calls currently installed function.
+    #[inline]
+    pub fn call(&self, a: A) -> B {
+        // Don't hold the lock while `f` runs, so `f` may `swap` the stage
+        let f = Arc::clone(&self.f.read().unwrap_or_else(PoisonError::into_inner));
+        f(a)
+    }
+
+    /// Installs `f` instead of the current function (for this stage and all
+    /// its clones).
+    ///
+    /// Calls that are already running finish with the old function, `swap`
+    /// doesn't wait for them (so it can also be called from inside the
+    /// installed function).
+    #[inline]
+    pub fn swap<F>(&self, f: F)
+    where
+        F: Fn(A) -> B + Send + Sync + 'static,
+    {
+        let old = {
+            let mut guard = self.f.write().unwrap_or_else(PoisonError::into_inner);
+            mem::replace(&mut *guard, Arc::new(f))
+        };
+        // Drop the old function outside of the lock
+        drop(old);
+    }
+}
+
+impl<A, B> Clone for SwappableStage<A, B> {
+    /// Returns stage sharing the installed function with `self`.
+    #[inline]
+    fn clone(&self) -> Self {
+        SwappableStage {
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+impl<A, B> Debug for SwappableStage<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("SwappableStage").finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B> FnOnce<(A,)> for SwappableStage<A, B> {
+    type Output = B;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+        SwappableStage::call(&self, a)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B> FnMut<(A,)> for SwappableStage<A, B> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+        SwappableStage::call(self, a)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B> Fn<(A,)> for SwappableStage<A, B> {
+    #[inline]
+    extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output { SwappableStage::call(self, a) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use crate::{compose, swap::SwappableStage};
+
+    #[test]
+    fn swap_in_pipeline() {
+        let stage = SwappableStage::new(|s: &str| s.to_uppercase());
+        let pipeline = {
+            let stage = stage.clone();
+            compose(|s: String| format!("<{}>", s), move |s| stage.call(s))
+        };
+
+        assert_eq!(pipeline("hi"), "<HI>");
+
+        stage.swap(|s: &str| s.chars().rev().collect());
+        assert_eq!(pipeline("hi"), "<ih>");
+    }
+
+    #[test]
+    fn swap_from_other_thread() {
+        let stage = SwappableStage::new(|x: u32| x);
+
+        let handle = {
+            let stage = stage.clone();
+            thread::spawn(move || stage.swap(|x: u32| x * 2))
+        };
+        handle.join().unwrap();
+
+        assert_eq!(stage.call(21), 42);
+    }
+
+    #[test]
+    fn swap_from_inside_stage() {
+        let handle: Arc<Mutex<Option<SwappableStage<u32, u32>>>> = Arc::default();
+        let stage = {
+            let handle = Arc::clone(&handle);
+            SwappableStage::new(move |x: u32| {
+                let stage = handle.lock().unwrap().take().unwrap();
+                stage.swap(|x: u32| x * 2);
+                x
+            })
+        };
+        *handle.lock().unwrap() = Some(stage.clone());
+
+        assert_eq!(stage.call(21), 21);
+        assert_eq!(stage.call(21), 42);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn call_as_fn() {
+        use crate::unstable::FnExt;
+
+        let stage = SwappableStage::new(|x: i32| x + 1);
+        let double = |x: i32| x * 2;
+        let pipeline = stage.clone().chain(double);
+
+        assert_eq!(pipeline(1), 4);
+        stage.swap(|x: i32| x - 1);
+        assert_eq!(pipeline(1), 0);
+    }
+}
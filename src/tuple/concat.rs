@@ -71,3 +71,28 @@ where
 }
 
 tuple_concat_impl::concat_impls!(A, B, C, D, E, F, G, H, I, J, K; T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10;);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::concat::TupleConcat;
+
+    #[test]
+    fn empty() {
+        assert_eq!(().concat(()), ());
+        assert_eq!(().concat((1,)), (1,));
+        assert_eq!((1, 2).concat(()), (1, 2));
+    }
+
+    #[test]
+    fn different_sizes() {
+        assert_eq!((1,).concat((2,)), (1, 2));
+        assert_eq!((1,).concat((2, 3, 4)), (1, 2, 3, 4));
+        assert_eq!((1, 2, 3).concat((4,)), (1, 2, 3, 4));
+        assert_eq!((1, 2, 3, 4).concat((5, 6, 7, 8)), (1, 2, 3, 4, 5, 6, 7, 8));
+    }
+
+    #[test]
+    fn heterogeneous() {
+        assert_eq!(("a", 1u8).concat(('c', true)), ("a", 1u8, 'c', true));
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+
+use crate::sealed::Sealed;
+
+/// Collects homogeneous tuple into a [`Vec`] removing consecutive repeated
+/// elements (like [`Vec::dedup`]).
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::dedup::TupleDedup;
+///
+/// assert_eq!((1, 1, 2, 2, 2, 3).dedup(), vec![1, 2, 3]);
+/// assert_eq!((1, 2, 1).dedup(), vec![1, 2, 1]);
+/// assert_eq!(("x",).dedup(), vec!["x"]);
+/// ```
+pub trait TupleDedup: Sized + Sealed {
+    /// Type of the elements of the tuple
+    type Item: PartialEq;
+
+    /// Collects elements of the tuple into a [`Vec`] removing consecutive
+    /// repeated elements.
+    fn dedup(self) -> Vec<Self::Item>;
+}
+
+macro_rules! tuple_impl {
+    ($( $types:ident, )*) => {
+        impl<T: PartialEq> TupleDedup for ($( replace_ident!($types, T), )*) {
+            type Item = T;
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn dedup(self) -> Vec<Self::Item> {
+                let ($( $types, )*) = self;
+                let mut vec = alloc::vec![$( $types, )*];
+                vec.dedup();
+                vec
+            }
+        }
+    };
+}
+
+for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, # tuple_impl);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::dedup::TupleDedup;
+
+    #[test]
+    fn runs() {
+        assert_eq!((1, 1).dedup(), [1]);
+        assert_eq!((1, 1, 2, 2, 2, 3).dedup(), [1, 2, 3]);
+        assert_eq!((4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4).dedup(), [4]);
+        assert_eq!(("a", "a", "b", "a", "a").dedup(), ["a", "b", "a"]);
+    }
+
+    #[test]
+    fn all_distinct() {
+        assert_eq!((1,).dedup(), [1]);
+        assert_eq!((1, 2, 3).dedup(), [1, 2, 3]);
+        assert_eq!(
+            (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12).dedup(),
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::sealed::Sealed;
+
+/// Flattens one level of tuple nesting (`((A, B), C) => (A, B, C)`).
+///
+/// `Shape` describes which elements of the outer tuple are nested tuples (see
+/// [`Nested`] and [`Flat`]), it's needed because some tuples can be flattened
+/// in different ways (e.g.: `((A, B), (C, D))` can become `(A, B, C, D)` or
+/// `(A, B, (C, D))`). Usually `Shape` is inferred.
+///
+/// Implemented for all shapes that produce tuples of arity 3 and 4.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::flatten::FlattenTuple;
+///
+/// assert_eq!(((1, 2), 3).flatten(), (1, 2, 3));
+/// assert_eq!((1, (2, 3)).flatten(), (1, 2, 3));
+/// assert_eq!((1, (2, 3), 4).flatten(), (1, 2, 3, 4));
+/// assert_eq!(((1, 2, 3), 4).flatten(), (1, 2, 3, 4));
+/// ```
+///
+/// When there are several ways to flatten a tuple, `Shape` needs to be
+/// specified:
+/// ```
+/// use fntools::tuple::flatten::{Flat, FlattenTuple, Nested};
+///
+/// let tuple = ((1, 2), (3, 4));
+///
+/// assert_eq!(
+///     FlattenTuple::<(Nested, Nested)>::flatten(tuple),
+///     (1, 2, 3, 4)
+/// );
+/// assert_eq!(
+///     FlattenTuple::<(Nested, Flat)>::flatten(tuple),
+///     (1, 2, (3, 4))
+/// );
+/// ```
+pub trait FlattenTuple<Shape>: Sized + Sealed {
+    /// Flat tuple
+    type Res;
+
+    /// Flatten tuple.
+    fn flatten(self) -> Self::Res;
+}
+
+/// [`FlattenTuple`] shape marker: element of the outer tuple is a tuple which
+/// is flattened.
+#[derive(Debug, Clone, Copy)]
+pub struct Nested;
+
+/// [`FlattenTuple`] shape marker: element of the outer tuple is left as is.
+#[derive(Debug, Clone, Copy)]
+pub struct Flat;
+
+macro_rules! flatten_impl {
+    ($shape:ty; $( $pat:tt ),+ => $( $res:ident ),+) => {
+        impl<$( $res, )+> FlattenTuple<$shape> for ($( flatten_impl!(@ty $pat), )+) {
+            type Res = ($( $res, )+);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn flatten(self) -> Self::Res {
+                let ($( flatten_impl!(@pat $pat), )+) = self;
+                ($( $res, )+)
+            }
+        }
+    };
+
+    (@ty ($( $types:ident ),+)) => { ($( $types, )+) };
+    (@ty $ty:ident) => { $ty };
+
+    (@pat ($( $types:ident ),+)) => { ($( $types, )+) };
+    (@pat $ty:ident) => { $ty };
+}
+
+// arity 3
+flatten_impl!((Nested, Flat); (A, B), C => A, B, C);
+flatten_impl!((Flat, Nested); A, (B, C) => A, B, C);
+
+// arity 4
+flatten_impl!((Nested, Flat); (A, B, C), D => A, B, C, D);
+flatten_impl!((Flat, Nested); A, (B, C, D) => A, B, C, D);
+flatten_impl!((Nested, Nested); (A, B), (C, D) => A, B, C, D);
+flatten_impl!((Nested, Flat, Flat); (A, B), C, D => A, B, C, D);
+flatten_impl!((Flat, Nested, Flat); A, (B, C), D => A, B, C, D);
+flatten_impl!((Flat, Flat, Nested); A, B, (C, D) => A, B, C, D);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::flatten::{Flat, FlattenTuple, Nested};
+
+    #[test]
+    fn left_nested() {
+        assert_eq!(((1, 2), 3).flatten(), (1, 2, 3));
+        assert_eq!(((1, 2, 3), 4).flatten(), (1, 2, 3, 4));
+        assert_eq!(((1, 2), 3, 4).flatten(), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn right_nested() {
+        assert_eq!((1, (2, 3)).flatten(), (1, 2, 3));
+        assert_eq!((1, (2, 3, 4)).flatten(), (1, 2, 3, 4));
+        assert_eq!((1, 2, (3, 4)).flatten(), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn middle_and_both() {
+        assert_eq!((1, (2, 3), 4).flatten(), (1, 2, 3, 4));
+        assert_eq!(
+            FlattenTuple::<(Nested, Nested)>::flatten(((1, 2), (3, 4))),
+            (1, 2, 3, 4)
+        );
+        assert_eq!(
+            FlattenTuple::<(Flat, Nested)>::flatten(((1, 2), (3, 4))),
+            ((1, 2), 3, 4)
+        );
+    }
+
+    #[test]
+    fn heterogeneous() {
+        assert_eq!((("a", 1u8), 'c').flatten(), ("a", 1u8, 'c'));
+    }
+}
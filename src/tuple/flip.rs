@@ -1,10 +1,13 @@
-#![allow(clippy::unit_cmp)]
+// the test module is kept next to the trait definition
+#![allow(clippy::unit_cmp, clippy::items_after_test_module)]
 
 use crate::sealed::Sealed;
 
 /// Flips tuple, so first element becomes last, last becomes first, 2-nd becomes
 /// 2-nd from the end and so on.
 ///
+/// Implemented for tuples of arity up to 12.
+///
 /// ## Examples
 /// ```
 /// use fntools::tuple::flip::FlipTuple;
@@ -57,6 +60,18 @@ mod tests {
             (12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1)
         );
     }
+
+    #[test]
+    fn arities_4_to_8() {
+        assert_eq!((1, 2, 3, 4).flip(), (4, 3, 2, 1));
+        assert_eq!((1, 2, 3, 4, 5).flip(), (5, 4, 3, 2, 1));
+        assert_eq!((1, 2, 3, 4, 5, 6).flip(), (6, 5, 4, 3, 2, 1));
+        assert_eq!((1, 2, 3, 4, 5, 6, 7).flip(), (7, 6, 5, 4, 3, 2, 1));
+        assert_eq!((1, 2, 3, 4, 5, 6, 7, 8).flip(), (8, 7, 6, 5, 4, 3, 2, 1));
+
+        // mixed types
+        assert_eq!((1, "a", 'b', 2.0).flip(), (2.0, 'b', "a", 1));
+    }
 }
 
 impl FlipTuple for () {
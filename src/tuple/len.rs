@@ -0,0 +1,30 @@
+use crate::sealed::Sealed;
+
+/// Number of elements in the tuple.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::len::TupleLen;
+///
+/// assert_eq!(<()>::LEN, 0);
+/// assert_eq!(<(i32,)>::LEN, 1);
+/// assert_eq!(<(i32, &str, bool)>::LEN, 3);
+/// ```
+pub trait TupleLen: Sealed {
+    /// Number of elements in the tuple
+    const LEN: usize;
+}
+
+impl TupleLen for () {
+    const LEN: usize = 0;
+}
+
+macro_rules! tuple_impl {
+    ($( $types:ident, )*) => {
+        impl<$( $types, )*> TupleLen for ($( $types, )*) {
+            const LEN: usize = [$( stringify!($types), )*].len();
+        }
+    };
+}
+
+for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, # tuple_impl);
@@ -0,0 +1,77 @@
+use crate::sealed::Sealed;
+
+/// Map every element of homogeneous tuple (like [`Iterator::map`], but
+/// fixed-size).
+///
+/// Returns tuple of the same arity with `f` applied to every element in
+/// order.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::map::TupleMap;
+///
+/// assert_eq!((1, 2, 3).map(|x| x * 2), (2, 4, 6));
+///
+/// // Element type may change
+/// assert_eq!(("a", "bc").map(str::len), (1, 2));
+/// ```
+pub trait TupleMap: Sized + Sealed {
+    /// Type of the elements of the tuple
+    type Item;
+
+    /// Tuple of mapped elements (`(R, R, ...)` with the same arity as `Self`)
+    type Res<R>;
+
+    /// Calls `f` on every element, collecting results into a tuple.
+    fn map<R, F>(self, f: F) -> Self::Res<R>
+    where
+        F: FnMut(Self::Item) -> R;
+}
+
+macro_rules! tuple_impl {
+    ($( $types:ident, )*) => {
+        impl<T> TupleMap for ($( replace_ident!($types, T), )*) {
+            type Item = T;
+            type Res<R> = ($( replace_ident!($types, R), )*);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn map<R, F>(self, mut f: F) -> Self::Res<R>
+            where
+                F: FnMut(Self::Item) -> R,
+            {
+                let ($( $types, )*) = self;
+                ($( f($types), )*)
+            }
+        }
+    };
+}
+
+for_tuples!(A, B, C, D, E, F, G, H, # tuple_impl);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::map::TupleMap;
+
+    #[test]
+    fn arities() {
+        assert_eq!((1,).map(|x| x + 1), (2,));
+        assert_eq!((1, 2).map(|x| x + 1), (2, 3));
+        assert_eq!(
+            (1, 2, 3, 4, 5, 6, 7, 8).map(|x| x * 10),
+            (10, 20, 30, 40, 50, 60, 70, 80)
+        );
+    }
+
+    #[test]
+    fn order() {
+        let mut seen = Vec::new();
+        let res = ('a', 'b', 'c').map(|x| {
+            seen.push(x);
+            x.to_ascii_uppercase()
+        });
+
+        assert_eq!(res, ('A', 'B', 'C'));
+        assert_eq!(seen, ['a', 'b', 'c']);
+    }
+}
@@ -13,7 +13,7 @@ use crate::{sealed::Sealed, tuple::push::TuplePush};
 /// ```
 ///
 /// ```compile_fail
-/// use fntools::tuple_pop::TuplePop;
+/// use fntools::tuple::pop::TuplePop;
 ///
 /// // There is nothing you can pop from empty tuple,
 /// // so this code won't be compiled
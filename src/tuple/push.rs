@@ -41,3 +41,31 @@ macro_rules! tuple_impl {
 }
 
 for_tuples!(A, B, C, D, E, F, G, H, I, J, K, # tuple_impl);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::{pop::TuplePop, push::TuplePush};
+
+    #[test]
+    fn push() {
+        assert_eq!(().push(0), (0,));
+        assert_eq!((0,).push(1), (0, 1));
+        assert_eq!((0, 1, 2).push(3), (0, 1, 2, 3));
+        assert_eq!((0, 1, 2, 3, 4, 5, 6).push(7), (0, 1, 2, 3, 4, 5, 6, 7));
+        assert_eq!(
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10).push(11),
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+        );
+    }
+
+    #[test]
+    fn heterogeneous() {
+        assert_eq!(().push("a").push(1u8).push('c'), ("a", 1u8, 'c'));
+    }
+
+    #[test]
+    fn push_pop_roundtrip() {
+        assert_eq!(().push(1).pop(), ((), 1));
+        assert_eq!((1, 2).push(3).pop(), ((1, 2), 3));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::sealed::Sealed;
+
+/// Running accumulation over homogeneous tuple (like [`Iterator::scan`], but
+/// fixed-size).
+///
+/// Returns tuple of the same arity with the accumulator values after every
+/// element.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::scan::TupleScan;
+///
+/// // Prefix sums
+/// assert_eq!((1, 2, 3).scan(0, |acc, x| acc + x), (1, 3, 6));
+///
+/// // Accumulator type may differ from elements type
+/// assert_eq!(
+///     ("a", "b", "c").scan(String::new(), |acc, x| format!("{}{}", acc, x)),
+///     (String::from("a"), String::from("ab"), String::from("abc"))
+/// );
+/// ```
+pub trait TupleScan<Acc>: Sized + Sealed {
+    /// Type of the elements of the tuple
+    type Item;
+
+    /// Tuple of accumulators (`(Acc, Acc, ...)` with the same arity as `Self`)
+    type Res;
+
+    /// Calls `f` on the current accumulator and every element, collecting
+    /// intermediate accumulators into a tuple.
+    fn scan<F>(self, init: Acc, f: F) -> Self::Res
+    where
+        F: FnMut(&Acc, Self::Item) -> Acc;
+}
+
+macro_rules! tuple_impl {
+    ($( $types:ident, )*) => {
+        impl<T, Acc: Clone> TupleScan<Acc> for ($( replace_ident!($types, T), )*) {
+            type Item = T;
+            type Res = ($( replace_ident!($types, Acc), )*);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn scan<F>(self, init: Acc, mut f: F) -> Self::Res
+            where
+                F: FnMut(&Acc, Self::Item) -> Acc,
+            {
+                let ($( $types, )*) = self;
+                let mut acc = init;
+                ($( {
+                    acc = f(&acc, $types);
+                    acc.clone()
+                }, )*)
+            }
+        }
+    };
+}
+
+for_tuples!(A, B, C, D, E, F, G, H, # tuple_impl);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::scan::TupleScan;
+
+    #[test]
+    fn prefix_sum() {
+        assert_eq!((1,).scan(0, |acc, x| acc + x), (1,));
+        assert_eq!((1, 2, 3).scan(0, |acc, x| acc + x), (1, 3, 6));
+        assert_eq!(
+            (1, 1, 1, 1, 1, 1, 1, 1).scan(10, |acc, x| acc + x),
+            (11, 12, 13, 14, 15, 16, 17, 18)
+        );
+    }
+
+    #[test]
+    fn order() {
+        let mut seen = Vec::new();
+        let res = ('a', 'b', 'c').scan(0, |acc, x| {
+            seen.push(x);
+            acc * 10 + (x as u32 - 'a' as u32 + 1)
+        });
+
+        assert_eq!(res, (1, 12, 123));
+        assert_eq!(seen, ['a', 'b', 'c']);
+    }
+}
@@ -13,7 +13,7 @@ use crate::{sealed::Sealed, tuple::append::TupleAppend};
 /// ```
 ///
 /// ```compile_fail
-/// use fntools::tuple_take::TupleTake;
+/// use fntools::tuple::take::TupleTake;
 ///
 /// // There is nothing you can take from empty tuple,
 /// // so this code won't be compiled
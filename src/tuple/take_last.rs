@@ -0,0 +1,74 @@
+use crate::{sealed::Sealed, tuple::pop::TuplePop};
+
+/// Takes element from the **end** of the tuple, producing new tuple.
+///
+/// Return tuple of taken element and remaining tuple (this is the same as
+/// [`TuplePop`], but with the order of the result swapped to match
+/// [`TupleTake`]).
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::take_last::TupleTakeLast;
+///
+/// assert_eq!((999,).take_last(), (999, ()));
+/// assert_eq!((47, "str", 14usize).take_last(), (14usize, (47, "str")));
+/// ```
+///
+/// ```compile_fail
+/// use fntools::tuple::take_last::TupleTakeLast;
+///
+/// // There is nothing you can take from empty tuple,
+/// // so this code won't be compiled
+/// assert_eq!(().take_last(), ());
+/// ```
+///
+/// [`TupleTake`]: crate::tuple::take::TupleTake
+pub trait TupleTakeLast: Sized + Sealed {
+    /// Remaining part of the tuple, after taking an element
+    type Rem;
+
+    /// Taken element
+    type Take;
+
+    /// Take last element from tuple.
+    fn take_last(self) -> (Self::Take, Self::Rem);
+}
+
+impl<T> TupleTakeLast for T
+where
+    T: TuplePop,
+{
+    type Rem = T::Rem;
+    type Take = T::Pop;
+
+    #[inline]
+    fn take_last(self) -> (Self::Take, Self::Rem) {
+        let (rem, take) = self.pop();
+        (take, rem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::take_last::TupleTakeLast;
+
+    #[test]
+    fn take_last() {
+        assert_eq!((1,).take_last(), (1, ()));
+        assert_eq!((1, 2).take_last(), (2, (1,)));
+        assert_eq!((1, 2, 3).take_last(), (3, (1, 2)));
+        assert_eq!(
+            (1, 2, 3, 4, 5, 6, 7, 8).take_last(),
+            (8, (1, 2, 3, 4, 5, 6, 7))
+        );
+        assert_eq!(
+            (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12).take_last(),
+            (12, (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11))
+        );
+    }
+
+    #[test]
+    fn heterogeneous() {
+        assert_eq!(("a", 1u8, 'c').take_last(), ('c', ("a", 1u8)));
+    }
+}
@@ -0,0 +1,152 @@
+/// Check output of a function in debug builds.
+///
+/// Takes function `f` and predicate `p` and returns function that calls `f`
+/// and, if `debug_assertions` are enabled, panics unless `p` holds for the
+/// output (in other words something _like_
+/// `|a: A| { let out = f(a); debug_assert!(p(&out)); out }`).
+///
+/// In release builds `p` is never called, so the result is just `f`. This
+/// makes it cheap to embed invariants (contracts) in pipelines.
+///
+/// # Panics
+///
+/// With `debug_assertions` enabled, the returned function panics if `p`
+/// returns `false` for the output of `f`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::debug_assert_output;
+///
+/// let abs = |x: i32| x.abs();
+/// let abs = debug_assert_output(abs, |out: &i32| *out >= 0);
+///
+/// assert_eq!(abs(-3), 3);
+/// assert_eq!(abs(4), 4);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::debug_assert_output`]
+///
+/// [`FnExt::debug_assert_output`]: crate::unstable::FnExt::debug_assert_output
+#[inline]
+pub fn debug_assert_output<A, F, P>(f: F, p: P) -> DebugAssertOutput<F, P>
+where
+    F: FnOnce<A>,
+    P: FnOnce(&F::Output) -> bool,
+{
+    DebugAssertOutput::new(f, p)
+}
+
+/// Represents function `F` with output checked by `P` in debug builds.
+///
+/// For documentation see [`debug_assert_output`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DebugAssertOutput<F, P> {
+    f: F,
+    p: P,
+}
+
+impl<F, P> DebugAssertOutput<F, P> {
+    /// Creates version of the function `f` with output checked by `p` in
+    /// debug builds.
+    ///
+    /// It's preferred to use [`debug_assert_output`] instead.
+    #[inline]
+    pub fn new<A>(f: F, p: P) -> Self
+    where
+        F: FnOnce<A>,
+        P: FnOnce(&F::Output) -> bool,
+    {
+        DebugAssertOutput { f, p }
+    }
+
+    /// Returns inner function and predicate.
+    #[inline]
+    pub fn into_inner(self) -> (F, P) {
+        let DebugAssertOutput { f, p } = self;
+        (f, p)
+    }
+
+    /// Returns references to inner function and predicate.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &P) {
+        let DebugAssertOutput { f, p } = self;
+        (f, p)
+    }
+}
+
+impl<A, F, P> FnOnce<A> for DebugAssertOutput<F, P>
+where
+    F: FnOnce<A>,
+    P: FnOnce(&F::Output) -> bool,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let DebugAssertOutput { f, p } = self;
+        let res = f.call_once(args);
+        debug_assert!(p(&res), "function output doesn't satisfy the predicate");
+        res
+    }
+}
+
+impl<A, F, P> FnMut<A> for DebugAssertOutput<F, P>
+where
+    F: FnMut<A>,
+    P: FnMut(&F::Output) -> bool,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let DebugAssertOutput { f, p } = self;
+        let res = f.call_mut(args);
+        debug_assert!(p(&res), "function output doesn't satisfy the predicate");
+        res
+    }
+}
+
+impl<A, F, P> Fn<A> for DebugAssertOutput<F, P>
+where
+    F: Fn<A>,
+    P: Fn(&F::Output) -> bool,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let DebugAssertOutput { f, p } = self;
+        let res = f.call(args);
+        debug_assert!(p(&res), "function output doesn't satisfy the predicate");
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::unstable::FnExt;
+
+    #[test]
+    fn predicate_only_in_debug() {
+        let checks = Cell::new(0);
+        let double = |x: i32| x * 2;
+        let even = |out: &i32| {
+            checks.set(checks.get() + 1);
+            out % 2 == 0
+        };
+        let fun = double.debug_assert_output(even);
+
+        assert_eq!(fun(3), 6);
+        assert_eq!(fun(4), 8);
+        assert_eq!(checks.get(), if cfg!(debug_assertions) { 2 } else { 0 });
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn violated() {
+        let neg = |x: i32| -x;
+        let fun = neg.debug_assert_output(|out: &i32| *out > 0);
+
+        fun(1);
+    }
+}
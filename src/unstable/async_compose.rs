@@ -0,0 +1,358 @@
+use core::{
+    fmt::{Debug, Error, Formatter},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Chain two async functions.
+///
+/// Takes async functions `f` and `g` and returns async function that awaits
+/// `f` and then awaits `g` on the result (in other words something _like_
+/// `|a: A| async { g(f(a).await).await }`).
+///
+/// When called by (unique) reference, `g` is cloned into every returned future
+/// so the future doesn't borrow the combinator.
+///
+/// The returned future is `Send` (or `Unpin`) if futures of `f` and `g` and
+/// `g` itself are, so it can be spawned on multithreaded executors.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::async_chain;
+/// # use std::{future::Future, pin::Pin, task::{Context, Poll, RawWaker, RawWakerVTable, Waker}};
+/// # fn block_on<F: Future>(fut: F) -> F::Output {
+/// #     fn raw() -> RawWaker { RawWaker::new(std::ptr::null(), &VTABLE) }
+/// #     static VTABLE: RawWakerVTable = RawWakerVTable::new(|_| raw(), |_| {}, |_| {}, |_| {});
+/// #     let waker = unsafe { Waker::from_raw(raw()) };
+/// #     let mut cx = Context::from_waker(&waker);
+/// #     let mut fut = Box::pin(fut);
+/// #     loop {
+/// #         if let Poll::Ready(res) = fut.as_mut().poll(&mut cx) { return res; }
+/// #     }
+/// # }
+///
+/// async fn parse(s: &str) -> i32 { s.parse().unwrap() }
+/// async fn double(x: i32) -> i32 { x * 2 }
+///
+/// let fun = async_chain(parse, double);
+///
+/// assert_eq!(block_on(fun("21")), 42);
+/// assert_eq!(block_on(fun("-1")), -2);
+/// ```
+///
+/// See also:
+/// - [`async_compose`]
+/// - sync version of this function: [`chain`]
+///
+/// [`chain`]: crate::unstable::chain
+#[inline]
+pub fn async_chain<A, F, G>(f: F, g: G) -> AsyncChain<F, G>
+where
+    F: FnOnce<A>,
+    F::Output: Future,
+    G: FnOnce<(<F::Output as Future>::Output,)>,
+    G::Output: Future,
+{
+    AsyncChain::new(f, g)
+}
+
+/// Compose two async functions.
+///
+/// Same as [`async_chain`], but with reversed argument order: the returned
+/// async function awaits `g` and then awaits `f` on the result.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::async_compose;
+/// # use std::{future::Future, pin::Pin, task::{Context, Poll, RawWaker, RawWakerVTable, Waker}};
+/// # fn block_on<F: Future>(fut: F) -> F::Output {
+/// #     fn raw() -> RawWaker { RawWaker::new(std::ptr::null(), &VTABLE) }
+/// #     static VTABLE: RawWakerVTable = RawWakerVTable::new(|_| raw(), |_| {}, |_| {}, |_| {});
+/// #     let waker = unsafe { Waker::from_raw(raw()) };
+/// #     let mut cx = Context::from_waker(&waker);
+/// #     let mut fut = Box::pin(fut);
+/// #     loop {
+/// #         if let Poll::Ready(res) = fut.as_mut().poll(&mut cx) { return res; }
+/// #     }
+/// # }
+///
+/// async fn inc(x: i32) -> i32 { x + 1 }
+/// async fn double(x: i32) -> i32 { x * 2 }
+///
+/// // execution order: double -> inc
+/// let fun = async_compose(inc, double);
+///
+/// assert_eq!(block_on(fun(20)), 41);
+/// ```
+///
+/// See also:
+/// - sync version of this function: [`compose`]
+///
+/// [`compose`]: crate::unstable::compose
+#[inline]
+pub fn async_compose<A, F, G>(f: F, g: G) -> AsyncChain<G, F>
+where
+    G: FnOnce<A>,
+    G::Output: Future,
+    F: FnOnce<(<G::Output as Future>::Output,)>,
+    F::Output: Future,
+{
+    AsyncChain::new(g, f)
+}
+
+/// Represents chain of 2 async functions `G ∘ F`.
+///
+/// For documentation see [`async_chain`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsyncChain<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> AsyncChain<F, G> {
+    /// Creates chain of async functions `f` and `g`.
+    ///
+    /// It's preferred to use [`async_chain`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A>,
+        F::Output: Future,
+        G: FnOnce<(<F::Output as Future>::Output,)>,
+        G::Output: Future,
+    {
+        AsyncChain { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let AsyncChain { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let AsyncChain { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G> FnOnce<A> for AsyncChain<F, G>
+where
+    F: FnOnce<A>,
+    F::Output: Future,
+    G: FnOnce<(<F::Output as Future>::Output,)>,
+    G::Output: Future,
+{
+    type Output = AsyncChainFuture<F::Output, G, G::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let AsyncChain { f, g } = self;
+        AsyncChainFuture::new(f.call_once(args), g)
+    }
+}
+
+impl<A, F, G> FnMut<A> for AsyncChain<F, G>
+where
+    F: FnMut<A>,
+    F::Output: Future,
+    G: FnMut<(<F::Output as Future>::Output,)> + Clone,
+    G::Output: Future,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let AsyncChain { f, g } = self;
+        AsyncChainFuture::new(f.call_mut(args), g.clone())
+    }
+}
+
+impl<A, F, G> Fn<A> for AsyncChain<F, G>
+where
+    F: Fn<A>,
+    F::Output: Future,
+    G: Fn<(<F::Output as Future>::Output,)> + Clone,
+    G::Output: Future,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let AsyncChain { f, g } = self;
+        AsyncChainFuture::new(f.call(args), g.clone())
+    }
+}
+
+/// Future returned by [`AsyncChain`], awaits `First` and then the future
+/// returned by `G`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AsyncChainFuture<First, G, Second> {
+    state: State<First, G, Second>,
+}
+
+enum State<First, G, Second> {
+    First(First, Option<G>),
+    Second(Second),
+    Done,
+}
+
+impl<First, G, Second> AsyncChainFuture<First, G, Second> {
+    #[inline]
+    fn new(first: First, g: G) -> Self {
+        AsyncChainFuture {
+            state: State::First(first, Some(g)),
+        }
+    }
+}
+
+impl<First, G, Second> Future for AsyncChainFuture<First, G, Second>
+where
+    First: Future,
+    G: FnOnce<(First::Output,), Output = Second>,
+    Second: Future,
+{
+    type Output = Second::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: futures in `state` are never moved out, they are only
+        // dropped in place (by overwriting `state`). `G` is not structurally
+        // pinned, so it's ok to move it out.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        loop {
+            match &mut this.state {
+                State::First(first, g) => {
+                    let b = match unsafe { Pin::new_unchecked(first) }.poll(cx) {
+                        Poll::Ready(b) => b,
+                        Poll::Pending => return Poll::Pending,
+                    };
+                    let g = g.take().expect("`g` is only taken once");
+                    this.state = State::Second(g.call_once((b,)));
+                },
+                State::Second(second) => {
+                    let c = match unsafe { Pin::new_unchecked(second) }.poll(cx) {
+                        Poll::Ready(c) => c,
+                        Poll::Pending => return Poll::Pending,
+                    };
+                    this.state = State::Done;
+                    return Poll::Ready(c);
+                },
+                State::Done => panic!("`AsyncChainFuture` polled after completion"),
+            }
+        }
+    }
+}
+
+impl<First, G, Second> Debug for AsyncChainFuture<First, G, Second> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let state = match self.state {
+            State::First(..) => "First",
+            State::Second(_) => "Second",
+            State::Done => "Done",
+        };
+        f.debug_struct("AsyncChainFuture")
+            .field("state", &state)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        future::Future,
+        pin::Pin,
+        ptr,
+        task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    };
+
+    use crate::unstable::{async_chain, async_compose};
+
+    fn noop_waker() -> Waker {
+        fn raw() -> RawWaker { RawWaker::new(ptr::null(), &VTABLE) }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(|_| raw(), |_| {}, |_| {}, |_| {});
+
+        unsafe { Waker::from_raw(raw()) }
+    }
+
+    /// Future that is pending `n` times before resolving to `value`
+    struct Delay<T> {
+        n: usize,
+        value: Option<T>,
+    }
+
+    impl<T: Unpin> Future for Delay<T> {
+        type Output = T;
+
+        fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<T> {
+            if self.n == 0 {
+                Poll::Ready(self.value.take().unwrap())
+            } else {
+                self.n -= 1;
+                Poll::Pending
+            }
+        }
+    }
+
+    fn delay<T>(n: usize, value: T) -> Delay<T> {
+        Delay {
+            n,
+            value: Some(value),
+        }
+    }
+
+    /// Polls `fut` to completion, returns output and number of polls
+    fn run<F: Future>(fut: F) -> (F::Output, usize) {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = Box::pin(fut);
+
+        let mut polls = 0;
+        loop {
+            polls += 1;
+            if let Poll::Ready(res) = fut.as_mut().poll(&mut cx) {
+                return (res, polls);
+            }
+        }
+    }
+
+    #[test]
+    fn pending_stages() {
+        let f = |x: i32| delay(2, x + 1);
+        let g = |x: i32| delay(3, x * 2);
+
+        assert_eq!(run(async_chain(f, g)(1)), (4, 6));
+        assert_eq!(run(async_compose(f, g)(1)), (3, 6));
+    }
+
+    #[test]
+    fn g_called_after_f() {
+        let log = Cell::new(0);
+        let f = |x: i32| {
+            assert_eq!(log.replace(1), 0);
+            delay(1, x)
+        };
+        let g = |x: i32| {
+            assert_eq!(log.replace(2), 1);
+            delay(0, x)
+        };
+
+        let fut = async_chain(f, g)(7);
+        assert_eq!(log.get(), 1);
+        assert_eq!(run(fut), (7, 2));
+        assert_eq!(log.get(), 2);
+    }
+
+    #[test]
+    fn send() {
+        fn assert_send<T: Send>(_: &T) {}
+
+        async fn inc(x: u8) -> u8 { x + 1 }
+
+        let fut = async_chain(inc, inc)(0);
+        assert_send(&fut);
+        assert_eq!(run(fut), (2, 1));
+    }
+}
@@ -0,0 +1,82 @@
+use alloc::boxed::Box;
+use core::fmt::{Debug, Error, Formatter};
+
+/// Boxed function of any number of arguments.
+///
+/// Long composition chains produce deeply nested unnameable types, `DynFn`
+/// erases them into a single nameable type which can be stored in a `Vec` or
+/// a struct field (at the cost of an allocation and dynamic dispatch).
+///
+/// The lifetime `'a` bounds what the function may borrow, use
+/// `DynFn<'static, Args, Output>` for functions that own all of their
+/// captures.
+///
+/// **NOTE**: this struct is under `#[cfg(feature = "alloc")]`
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::{DynFn, FnExt};
+///
+/// let inc = |x: i32| x + 1;
+/// let double = |x: i32| x * 2;
+/// let funs: Vec<DynFn<(i32,), i32>> =
+///     vec![inc.chain(double).boxed(), inc.compose(double).boxed()];
+///
+/// let results: Vec<_> = funs.iter().map(|f| f(3)).collect();
+/// assert_eq!(results, [8, 7]);
+/// ```
+///
+/// See also:
+/// - boxed function of one argument in stable: [`fntools::BoxFn`]
+/// - extension on all functions: [`FnExt::boxed`]
+///
+/// [`fntools::BoxFn`]: crate::BoxFn
+/// [`FnExt::boxed`]: crate::unstable::FnExt::boxed
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct DynFn<'a, Args, Output> {
+    f: Box<dyn Fn<Args, Output = Output> + 'a>,
+}
+
+impl<'a, Args, Output> DynFn<'a, Args, Output> {
+    /// Boxes function `f`.
+    ///
+    /// It's preferred to use [`FnExt::boxed`] instead.
+    ///
+    /// [`FnExt::boxed`]: crate::unstable::FnExt::boxed
+    #[inline]
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn<Args, Output = Output> + 'a,
+    {
+        DynFn { f: Box::new(f) }
+    }
+
+    /// Returns inner boxed function.
+    #[inline]
+    pub fn into_inner(self) -> Box<dyn Fn<Args, Output = Output> + 'a> { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &(dyn Fn<Args, Output = Output> + 'a) { &*self.f }
+}
+
+impl<Args, Output> Debug for DynFn<'_, Args, Output> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> { f.debug_struct("DynFn").finish() }
+}
+
+impl<Args, Output> FnOnce<Args> for DynFn<'_, Args, Output> {
+    type Output = Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: Args) -> Self::Output { self.f.call(args) }
+}
+
+impl<Args, Output> FnMut<Args> for DynFn<'_, Args, Output> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output { self.f.call(args) }
+}
+
+impl<Args, Output> Fn<Args> for DynFn<'_, Args, Output> {
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output { self.f.call(args) }
+}
@@ -0,0 +1,420 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Cache the most recent `N` results of a function.
+///
+/// Returns function that remembers outputs for the last `N` distinct
+/// arguments it was called with. When an argument is in the cache, the cached
+/// output is cloned instead of calling `f`. When the cache is full, the oldest
+/// argument is evicted (so it will be recomputed on the next call).
+///
+/// Unlike unbounded memoization, memory use is predictable: at most `N`
+/// argument/output pairs are stored.
+///
+/// **NOTE**: this function is under `#[cfg(feature = "std")]`
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::cache_n;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let square = |x: u64| {
+///     calls.set(calls.get() + 1);
+///     x * x
+/// };
+/// let square = cache_n::<2, _, _>(square);
+///
+/// assert_eq!(square(3), 9);
+/// assert_eq!(square(3), 9);
+/// assert_eq!(calls.get(), 1);
+///
+/// assert_eq!(square(4), 16);
+/// assert_eq!(square(5), 25); // evicts `3`
+/// assert_eq!(square(3), 9);
+/// assert_eq!(calls.get(), 4);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::cache_n`]
+///
+/// [`FnExt::cache_n`]: crate::unstable::FnExt::cache_n
+#[inline]
+pub fn cache_n<const N: usize, A, F>(f: F) -> CacheN<F, A, F::Output, N>
+where
+    F: FnOnce<A>,
+    A: Hash + Eq + Clone,
+    F::Output: Clone,
+{
+    CacheN::new(f)
+}
+
+/// Represents function `F` with the most recent `N` results cached.
+///
+/// For documentation see [`cache_n`].
+///
+/// **NOTE**: this struct is under `#[cfg(feature = "std")]`
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone)]
+pub struct CacheN<F, A, R, const N: usize> {
+    f: F,
+    cache: RefCell<Ring<A, R>>,
+}
+
+impl<F, A, R, const N: usize> CacheN<F, A, R, N> {
+    /// Creates version of the function `f` with the most recent `N` results
+    /// cached.
+    ///
+    /// It's preferred to use [`cache_n`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self
+    where
+        F: FnOnce<A, Output = R>,
+        A: Hash + Eq + Clone,
+        R: Clone,
+    {
+        CacheN {
+            f,
+            cache: RefCell::new(Ring::with_capacity(N)),
+        }
+    }
+
+    /// Returns inner function, dropping the cache.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<F, A, R, const N: usize> FnOnce<A> for CacheN<F, A, R, N>
+where
+    F: FnOnce<A, Output = R>,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    type Output = R;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let CacheN { f, cache } = self;
+        match cache.into_inner().take(&args) {
+            Some(res) => res,
+            None => f.call_once(args),
+        }
+    }
+}
+
+impl<F, A, R, const N: usize> FnMut<A> for CacheN<F, A, R, N>
+where
+    F: FnMut<A, Output = R>,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let CacheN { f, cache } = self;
+        let cache = cache.get_mut();
+        if let Some(res) = cache.get(&args) {
+            return res.clone();
+        }
+
+        let res = f.call_mut(args.clone());
+        cache.insert::<N>(args, res.clone());
+        res
+    }
+}
+
+impl<F, A, R, const N: usize> Fn<A> for CacheN<F, A, R, N>
+where
+    F: Fn<A, Output = R>,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        if let Some(res) = self.cache.borrow().get(&args) {
+            return res.clone();
+        }
+
+        // Note: the cache isn't borrowed while `f` is running, so `f` may
+        // (indirectly) call this function again.
+        let res = self.f.call(args.clone());
+        self.cache.borrow_mut().insert::<N>(args, res.clone());
+        res
+    }
+}
+
+/// Memoize a function.
+///
+/// Returns function that remembers outputs for every distinct argument it was
+/// called with. When an argument was already seen, the cached output is cloned
+/// instead of calling `f` again.
+///
+/// The cache is unbounded, for a bounded version see [`cache_n`].
+///
+/// **NOTE**: this function is under `#[cfg(feature = "std")]`
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::memoize;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let square = |x: u64| {
+///     calls.set(calls.get() + 1);
+///     x * x
+/// };
+/// let square = memoize(square);
+///
+/// assert_eq!(square(3), 9);
+/// assert_eq!(square(4), 16);
+/// assert_eq!(square(3), 9);
+/// assert_eq!(calls.get(), 2);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::memoize`]
+///
+/// [`FnExt::memoize`]: crate::unstable::FnExt::memoize
+#[inline]
+pub fn memoize<A, F>(f: F) -> Memoize<F, A, F::Output>
+where
+    F: FnOnce<A>,
+    A: Hash + Eq + Clone,
+    F::Output: Clone,
+{
+    Memoize::new(f)
+}
+
+/// Represents memoized function `F`.
+///
+/// For documentation see [`memoize`].
+///
+/// **NOTE**: this struct is under `#[cfg(feature = "std")]`
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone)]
+pub struct Memoize<F, A, R> {
+    f: F,
+    cache: RefCell<HashMap<A, R>>,
+}
+
+impl<F, A, R> Memoize<F, A, R> {
+    /// Creates memoized version of the function `f`.
+    ///
+    /// It's preferred to use [`memoize`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self
+    where
+        F: FnOnce<A, Output = R>,
+        A: Hash + Eq + Clone,
+        R: Clone,
+    {
+        Memoize {
+            f,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns inner function, dropping the cache.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<F, A, R> FnOnce<A> for Memoize<F, A, R>
+where
+    F: FnOnce<A, Output = R>,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    type Output = R;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let Memoize { f, cache } = self;
+        match cache.into_inner().remove(&args) {
+            Some(res) => res,
+            None => f.call_once(args),
+        }
+    }
+}
+
+impl<F, A, R> FnMut<A> for Memoize<F, A, R>
+where
+    F: FnMut<A, Output = R>,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Memoize { f, cache } = self;
+        let cache = cache.get_mut();
+        if let Some(res) = cache.get(&args) {
+            return res.clone();
+        }
+
+        let res = f.call_mut(args.clone());
+        cache.insert(args, res.clone());
+        res
+    }
+}
+
+impl<F, A, R> Fn<A> for Memoize<F, A, R>
+where
+    F: Fn<A, Output = R>,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        if let Some(res) = self.cache.borrow().get(&args) {
+            return res.clone();
+        }
+
+        // Note: the cache isn't borrowed while `f` is running, so `f` may
+        // (indirectly) call this function again.
+        let res = self.f.call(args.clone());
+        self.cache.borrow_mut().insert(args, res.clone());
+        res
+    }
+}
+
+/// Bounded cache which evicts the oldest entry when full.
+#[derive(Debug, Clone)]
+struct Ring<K, V> {
+    map: HashMap<K, V>,
+    /// Keys in the insertion order (oldest first).
+    order: VecDeque<K>,
+}
+
+impl<K, V> Ring<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn with_capacity(cap: usize) -> Self {
+        Ring {
+            map: HashMap::with_capacity(cap),
+            order: VecDeque::with_capacity(cap),
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<&V> { self.map.get(key) }
+
+    fn take(mut self, key: &K) -> Option<V> { self.map.remove(key) }
+
+    fn insert<const N: usize>(&mut self, key: K, value: V) {
+        if N == 0 {
+            return;
+        }
+
+        // a re-entrant call may have already inserted the same key
+        if let Some(old) = self.map.get_mut(&key) {
+            *old = value;
+            return;
+        }
+
+        if self.order.len() == N {
+            if let Some(oldest) = self.order.pop_front() {
+                self.map.remove(&oldest);
+            }
+        }
+
+        self.order.push_back(key.clone());
+        self.map.insert(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use crate::unstable::FnExt;
+
+    #[test]
+    fn evicts_oldest() {
+        let calls = RefCell::new(Vec::new());
+        let fun = (|x: i32| {
+            calls.borrow_mut().push(x);
+            x + 1
+        })
+        .cache_n::<3>();
+
+        // N + 1 distinct inputs
+        for x in 0..4 {
+            assert_eq!(fun(x), x + 1);
+        }
+        assert_eq!(*calls.borrow(), [0, 1, 2, 3]);
+
+        // the newest N hit the cache
+        for x in 1..4 {
+            assert_eq!(fun(x), x + 1);
+        }
+        assert_eq!(*calls.borrow(), [0, 1, 2, 3]);
+
+        // the oldest is recomputed
+        assert_eq!(fun(0), 1);
+        assert_eq!(*calls.borrow(), [0, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn reentrant_same_key() {
+        let calls = RefCell::new(Vec::new());
+        let this: Cell<Option<&dyn Fn(i32) -> i32>> = Cell::new(None);
+        let fun = (|x: i32| {
+            calls.borrow_mut().push(x);
+            // the first call of `1` calls itself with the same argument
+            if x == 1 && calls.borrow().len() == 1 {
+                this.get().unwrap()(1);
+            }
+            x + 1
+        })
+        .cache_n::<2>();
+        this.set(Some(&fun));
+
+        assert_eq!(fun(1), 2);
+        assert_eq!(fun(2), 3);
+        assert_eq!(*calls.borrow(), [1, 1, 2]);
+
+        // `1` is still cached, only two distinct keys were seen
+        assert_eq!(fun(1), 2);
+        assert_eq!(*calls.borrow(), [1, 1, 2]);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let calls = RefCell::new(0);
+        let fun = (|x: i32| {
+            *calls.borrow_mut() += 1;
+            x
+        })
+        .cache_n::<0>();
+
+        fun(1);
+        fun(1);
+        assert_eq!(*calls.borrow(), 2);
+    }
+
+    #[test]
+    fn memoize_multiple_args() {
+        let calls = RefCell::new(Vec::new());
+        let fun = (|a: i32, b: i32| {
+            calls.borrow_mut().push((a, b));
+            a - b
+        })
+        .memoize();
+
+        assert_eq!(fun(3, 1), 2);
+        assert_eq!(fun(1, 3), -2);
+        assert_eq!(fun(3, 1), 2);
+        assert_eq!(fun(1, 3), -2);
+        assert_eq!(*calls.borrow(), [(3, 1), (1, 3)]);
+    }
+}
@@ -0,0 +1,145 @@
+use core::cell::RefCell;
+
+/// Cache the last result of a function.
+///
+/// Returns function that remembers the most recent argument and output. When
+/// it's called with the same argument again, the cached output is cloned
+/// instead of calling `f`, any other argument replaces the cached pair.
+///
+/// This is much cheaper than [`memoize`] (only one pair is stored and
+/// arguments are compared with `==` instead of hashing), so it's useful for
+/// functions that are often called repeatedly with the same argument.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::cache_last;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let square = |x: u64| {
+///     calls.set(calls.get() + 1);
+///     x * x
+/// };
+/// let square = cache_last(square);
+///
+/// assert_eq!(square(3), 9);
+/// assert_eq!(square(3), 9);
+/// assert_eq!(calls.get(), 1); // skipped on a repeated argument
+///
+/// assert_eq!(square(4), 16);
+/// assert_eq!(square(3), 9);
+/// assert_eq!(calls.get(), 3); // re-run on changed ones
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::cache_last`]
+///
+/// [`memoize`]: crate::unstable::memoize
+/// [`FnExt::cache_last`]: crate::unstable::FnExt::cache_last
+#[inline]
+pub fn cache_last<A, F>(f: F) -> CacheLast<F, A, F::Output>
+where
+    F: FnOnce<A>,
+    A: PartialEq + Clone,
+    F::Output: Clone,
+{
+    CacheLast::new(f)
+}
+
+/// Represents function `F` with the last result cached.
+///
+/// For documentation see [`cache_last`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone)]
+pub struct CacheLast<F, A, R> {
+    f: F,
+    last: RefCell<Option<(A, R)>>,
+}
+
+impl<F, A, R> CacheLast<F, A, R> {
+    /// Creates version of the function `f` with the last result cached.
+    ///
+    /// It's preferred to use [`cache_last`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self
+    where
+        F: FnOnce<A, Output = R>,
+        A: PartialEq + Clone,
+        R: Clone,
+    {
+        CacheLast {
+            f,
+            last: RefCell::new(None),
+        }
+    }
+
+    /// Returns inner function, dropping the cache.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<F, A, R> FnOnce<A> for CacheLast<F, A, R>
+where
+    F: FnOnce<A, Output = R>,
+    A: PartialEq + Clone,
+    R: Clone,
+{
+    type Output = R;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let CacheLast { f, last } = self;
+        match last.into_inner() {
+            Some((a, res)) if a == args => res,
+            _ => f.call_once(args),
+        }
+    }
+}
+
+impl<F, A, R> FnMut<A> for CacheLast<F, A, R>
+where
+    F: FnMut<A, Output = R>,
+    A: PartialEq + Clone,
+    R: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let CacheLast { f, last } = self;
+        let last = last.get_mut();
+        if let Some((a, res)) = last {
+            if *a == args {
+                return res.clone();
+            }
+        }
+
+        let res = f.call_mut(args.clone());
+        *last = Some((args, res.clone()));
+        res
+    }
+}
+
+impl<F, A, R> Fn<A> for CacheLast<F, A, R>
+where
+    F: Fn<A, Output = R>,
+    A: PartialEq + Clone,
+    R: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        if let Some((a, res)) = &*self.last.borrow() {
+            if *a == args {
+                return res.clone();
+            }
+        }
+
+        // Note: the cache isn't borrowed while `f` is running, so `f` may
+        // (indirectly) call this function again.
+        let res = self.f.call(args.clone());
+        *self.last.borrow_mut() = Some((args, res.clone()));
+        res
+    }
+}
@@ -14,11 +14,31 @@
 /// assert_eq!(add_five(4), 9);
 /// ```
 ///
+/// ## Outputs and arguments
+///
+/// Output of `f` is always passed to `g` as its **only** argument, whatever
+/// the type of the output is. So a function returning bare `T` chains into
+/// a function of one argument `T`, and a function returning a tuple `(T, U)`
+/// chains into a function of one argument `(T, U)`. To spread a tuple output
+/// over several arguments use [`untuple`] (or [`FnExt::chain_ut`]):
+/// ```
+/// use fntools::unstable::{chain, untuple};
+///
+/// let div_rem = |a: i32, b: i32| (a / b, a % b);
+/// let show = |(d, r): (i32, i32)| format!("{} rem {}", d, r);
+/// let add = |d: i32, r: i32| d + r;
+///
+/// assert_eq!(chain(div_rem, show)(7, 2), "3 rem 1");
+/// assert_eq!(chain(div_rem, untuple(add))(7, 2), 4);
+/// ```
+///
 /// See also:
 /// - stable version of this function: [`fntools::chain`]
 /// - extension on all functions: [`FnExt::chain`]
 ///
 /// [`FnExt::chain`]: crate::unstable::FnExt::chain
+/// [`FnExt::chain_ut`]: crate::unstable::FnExt::chain_ut
+/// [`untuple`]: crate::unstable::untuple
 /// [`fntools::chain`]: crate::chain
 #[inline]
 pub fn chain<A, F, G>(f: F, g: G) -> Chain<F, G>
@@ -37,10 +57,10 @@ where
 ///
 /// [`Compose`]: crate::unstable::Compose
 #[must_use = "function combinators are lazy and do nothing unless called"]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Chain<F, G> {
-    f: F,
-    g: G,
+    pub(super) f: F,
+    pub(super) g: G,
 }
 
 impl<F, G> Chain<F, G> {
@@ -114,3 +134,35 @@ where
         c
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::{chain, FnExt};
+
+    #[test]
+    fn bare_output() {
+        let len = |s: &str| s.len();
+        let double = |n: usize| n * 2;
+
+        assert_eq!(chain(len, double)("abc"), 6);
+        assert_eq!(str::len.chain(double)("abcd"), 8);
+    }
+
+    #[test]
+    fn one_tuple_output() {
+        let wrap = |a: i32| (a,);
+        let unwrap = |(a,): (i32,)| a;
+
+        assert_eq!(chain(wrap, unwrap)(17), 17);
+        // spread into a function of one argument
+        assert_eq!(wrap.chain_ut(|a: i32| a + 1)(17), 18);
+    }
+
+    #[test]
+    fn unit_output() {
+        let nothing = |_: i32| ();
+        let _: () = chain(nothing, |()| ())(1);
+
+        assert_eq!(nothing.chain_ut(|| 42)(1), 42);
+    }
+}
@@ -0,0 +1,140 @@
+use core::ops::{Shl, Shr};
+
+use crate::unstable::{chain::Chain, compose::Compose};
+
+/// Enable operator syntax for composition.
+///
+/// Takes function `f` and returns the same function wrapped in
+/// [`Composable`], which supports `>>` ([`chain`]) and `<<` ([`compose`]):
+/// - `composable(f) >> g` is `g ∘ f` (i.e. `|a| g(f(a))`)
+/// - `composable(f) << g` is `f ∘ g` (i.e. `|a| f(g(a))`)
+///
+/// The result of both operators is also [`Composable`], so they can be
+/// chained further.
+///
+/// ## Why newtype?
+///
+/// Operators are traits from `core::ops`, and because of the orphan rules
+/// it's impossible to implement them for _all_ functions (`impl<F: Fn(..)>
+/// Shr<G> for F` is a blanket impl of a foreign trait) or for closures and
+/// fn items directly. So only the left-hand side of the operator needs to be
+/// wrapped, the right-hand side can be any function.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::composable;
+///
+/// let add_two = composable(|a: i32| a + 2);
+/// let add_three = |a: i32| a + 3;
+///
+/// assert_eq!((add_two >> add_three)(4), 9);
+///
+/// let to_string = composable(|a: i32| a.to_string());
+/// let pipeline = to_string << add_three >> |s: String| s + "!";
+///
+/// assert_eq!(pipeline(4), "7!");
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::composable`]
+///
+/// [`chain`]: crate::unstable::chain
+/// [`compose`]: crate::unstable::compose()
+/// [`FnExt::composable`]: crate::unstable::FnExt::composable
+#[inline]
+pub fn composable<F>(f: F) -> Composable<F> { Composable::new(f) }
+
+/// Represents function `F` which can be composed with operators.
+///
+/// For documentation see [`composable`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Composable<F> {
+    f: F,
+}
+
+impl<F> Composable<F> {
+    /// Wraps the function `f` to enable operator syntax.
+    ///
+    /// It's preferred to use [`composable`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self { Composable { f } }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+/// `composable(f) >> g` is the same as `Composable::new(chain(f, g))`.
+impl<F, G> Shr<G> for Composable<F> {
+    type Output = Composable<Chain<F, G>>;
+
+    #[inline]
+    fn shr(self, g: G) -> Self::Output { Composable::new(Chain { f: self.f, g }) }
+}
+
+/// `composable(f) << g` is the same as `Composable::new(compose(f, g))`.
+impl<F, G> Shl<G> for Composable<F> {
+    type Output = Composable<Compose<F, G>>;
+
+    #[inline]
+    fn shl(self, g: G) -> Self::Output { Composable::new(Compose { f: self.f, g }) }
+}
+
+impl<A, F> FnOnce<A> for Composable<F>
+where
+    F: FnOnce<A>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output { self.f.call_once(args) }
+}
+
+impl<A, F> FnMut<A> for Composable<F>
+where
+    F: FnMut<A>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output { self.f.call_mut(args) }
+}
+
+impl<A, F> Fn<A> for Composable<F>
+where
+    F: Fn<A>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output { self.f.call(args) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::{composable, FnExt};
+
+    #[test]
+    fn order() {
+        let push = |c: char| {
+            move |mut s: String| {
+                s.push(c);
+                s
+            }
+        };
+        let a = composable(push('a'));
+        let b = push('b');
+
+        assert_eq!((a >> b)(String::new()), "ab");
+        assert_eq!((a << b)(String::new()), "ba");
+    }
+
+    #[test]
+    fn multiple_args() {
+        let add = |a: i32, b: i32| a + b;
+        let fun = add.composable() >> (|x: i32| x * 10) >> (|x: i32| x + 1);
+
+        assert_eq!(fun(1, 2), 31);
+    }
+}
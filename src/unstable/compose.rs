@@ -23,7 +23,7 @@
 ///
 /// [`FnExt::compose`]: crate::unstable::FnExt::compose
 /// [`compose`]: super::compose::compose
-/// [`fntools::compose`]: crate::compose
+/// [`fntools::compose`]: crate::compose()
 #[inline]
 pub fn compose<A, F, G>(f: F, g: G) -> Compose<F, G>
 where
@@ -41,10 +41,10 @@ where
 ///
 /// [`Chain`]: crate::unstable::Chain
 #[must_use = "function combinators are lazy and do nothing unless called"]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Compose<F, G> {
-    f: F,
-    g: G,
+    pub(super) f: F,
+    pub(super) g: G,
 }
 
 impl<F, G> Compose<F, G> {
@@ -118,3 +118,55 @@ where
         c
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::compose;
+
+    fn inc(x: i32) -> i32 { x + 1 }
+    fn double(x: i32) -> i32 { x * 2 }
+
+    #[test]
+    fn debug() {
+        let f: fn(i32) -> i32 = inc;
+        let g: fn(i32) -> i32 = double;
+
+        assert_eq!(
+            format!("{:?}", compose(f, g)),
+            format!("Compose {{ f: {:?}, g: {:?} }}", f, g)
+        );
+    }
+
+    #[test]
+    fn copy() {
+        let offset = 10;
+        let fun = compose(move |x: i32| x + offset, |x: i32| x * 2);
+        let copy = fun;
+
+        assert_eq!(fun(1), 12);
+        assert_eq!(copy(2), 14);
+    }
+
+    #[test]
+    fn clone() {
+        let prefix = String::from("> ");
+        let fun = compose(move |s: String| prefix.clone() + &s, |x: i32| x.to_string());
+        let clone = fun.clone();
+
+        assert_eq!(fun(1), "> 1");
+        assert_eq!(clone(2), "> 2");
+    }
+
+    #[test]
+    fn fn_items() {
+        use core::ops::{Add, Mul};
+
+        use crate::unstable::{chain, FnExt};
+
+        // fn items (including methods) are accepted as is, no `as fn(..)` casts
+        assert_eq!(compose(i32::abs, i32::add)(-5, 2), 3);
+        assert_eq!(chain(i32::mul, i32::signum)(-6, 7), -1);
+        assert_eq!(str::trim.chain(str::len)("  hi  "), 2);
+        assert_eq!(inc.compose(double).chain(double)(1), 6);
+    }
+}
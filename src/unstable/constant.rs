@@ -0,0 +1,72 @@
+/// Function that ignores any number of arguments and returns `value`.
+///
+/// When called by (unique) reference, `value` is cloned.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::constant;
+///
+/// let seven = constant(7);
+///
+/// assert_eq!(seven(), 7);
+/// assert_eq!(seven("ignored"), 7);
+/// assert_eq!(seven(1, 2.0), 7);
+/// ```
+///
+/// As a placeholder:
+/// ```
+/// use fntools::unstable::{constant, FnExt};
+///
+/// let len = |s: &str| s.len();
+/// let fun = len.chain(constant("done"));
+///
+/// assert_eq!(fun("abc"), "done");
+/// ```
+#[inline]
+pub fn constant<T>(value: T) -> Const<T> { Const::new(value) }
+
+/// Represents function that ignores its arguments and returns `T`.
+///
+/// For documentation see [`constant`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Const<T>(T);
+
+impl<T> Const<T> {
+    /// Creates function that returns `value`.
+    ///
+    /// It's preferred to use [`constant`] instead.
+    #[inline]
+    pub fn new(value: T) -> Self { Const(value) }
+
+    /// Returns inner value.
+    #[inline]
+    pub fn into_inner(self) -> T { self.0 }
+
+    /// Returns reference to inner value.
+    #[inline]
+    pub fn as_inner(&self) -> &T { &self.0 }
+}
+
+impl<A, T> FnOnce<A> for Const<T> {
+    type Output = T;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, _args: A) -> Self::Output { self.0 }
+}
+
+impl<A, T> FnMut<A> for Const<T>
+where
+    T: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, _args: A) -> Self::Output { self.0.clone() }
+}
+
+impl<A, T> Fn<A> for Const<T>
+where
+    T: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, _args: A) -> Self::Output { self.0.clone() }
+}
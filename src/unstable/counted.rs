@@ -0,0 +1,137 @@
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+/// Count calls of a function.
+///
+/// Takes function `f` and returns function that increments a shared counter
+/// on every call before calling `f`, together with a handle to that counter.
+/// The handle can be used to check how many times a stage of a pipeline ran
+/// (e.g. in tests or diagnostics).
+///
+/// **NOTE**: this function is under `#[cfg(feature = "alloc")]`
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::{counted, FnExt};
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let (parse, calls) = counted(parse);
+/// let or_zero = |res: Result<i32, _>| res.unwrap_or(0);
+/// let fun = parse.chain(or_zero);
+///
+/// assert_eq!(fun("1"), 1);
+/// assert_eq!(fun("x"), 0);
+/// assert_eq!(fun("3"), 3);
+/// assert_eq!(calls.get(), 3);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::counted`]
+///
+/// [`FnExt::counted`]: crate::unstable::FnExt::counted
+#[inline]
+pub fn counted<A, F>(f: F) -> (Counted<F>, Rc<Cell<usize>>)
+where
+    F: FnOnce<A>,
+{
+    let count = Rc::new(Cell::new(0));
+    (Counted::new(f, Rc::clone(&count)), count)
+}
+
+/// Represents function `F` which counts its calls.
+///
+/// For documentation see [`counted`].
+///
+/// **NOTE**: this struct is under `#[cfg(feature = "alloc")]`
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone)]
+pub struct Counted<F> {
+    f: F,
+    count: Rc<Cell<usize>>,
+}
+
+impl<F> Counted<F> {
+    /// Creates version of the function `f` which increments `count` on every
+    /// call.
+    ///
+    /// It's preferred to use [`counted`] instead.
+    #[inline]
+    pub fn new<A>(f: F, count: Rc<Cell<usize>>) -> Self
+    where
+        F: FnOnce<A>,
+    {
+        Counted { f, count }
+    }
+
+    /// Returns number of calls so far.
+    #[inline]
+    pub fn count(&self) -> usize { self.count.get() }
+
+    /// Returns inner function and the counter.
+    #[inline]
+    pub fn into_inner(self) -> (F, Rc<Cell<usize>>) {
+        let Counted { f, count } = self;
+        (f, count)
+    }
+
+    /// Returns references to inner function and the counter.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &Rc<Cell<usize>>) {
+        let Counted { f, count } = self;
+        (f, count)
+    }
+}
+
+impl<A, F> FnOnce<A> for Counted<F>
+where
+    F: FnOnce<A>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let Counted { f, count } = self;
+        count.set(count.get() + 1);
+        f.call_once(args)
+    }
+}
+
+impl<A, F> FnMut<A> for Counted<F>
+where
+    F: FnMut<A>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Counted { f, count } = self;
+        count.set(count.get() + 1);
+        f.call_mut(args)
+    }
+}
+
+impl<A, F> Fn<A> for Counted<F>
+where
+    F: Fn<A>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let Counted { f, count } = self;
+        count.set(count.get() + 1);
+        f.call(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::FnExt;
+
+    #[test]
+    fn clones_share_counter() {
+        let (fun, calls) = (|x: i32| x * 2).counted();
+        let clone = fun.clone();
+
+        assert_eq!(fun(1), 2);
+        assert_eq!(clone(2), 4);
+        assert_eq!(calls.get(), 2);
+        assert_eq!(fun.count(), 2);
+    }
+}
@@ -1,9 +1,13 @@
-use std::{
+use core::{
     fmt::{Debug, Error, Formatter},
+    hash::{Hash, Hasher},
     marker::PhantomData,
 };
 
-use crate::tuple::{at_least_2::AtLeast2, push::TuplePush, take::TupleTake};
+use crate::{
+    tuple::{at_least_2::AtLeast2, len::TupleLen, push::TuplePush, take::TupleTake},
+    unstable::{uncurry::CallCurried, Supply},
+};
 
 /// Curring.
 ///
@@ -73,6 +77,47 @@ impl<S, F, Rem> Curry<S, F, Rem> {
         } = self;
         (supplied, f)
     }
+
+    /// Returns number of arguments that are yet to be supplied.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::curry;
+    ///
+    /// let fun = curry(|a: i32, b: i32, c: i32| a + b + c);
+    /// assert_eq!(fun.remaining(), 3);
+    /// assert_eq!(fun(1).remaining(), 2);
+    /// assert_eq!(fun(1)(2).remaining(), 1);
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> usize
+    where
+        Rem: TupleLen,
+    {
+        Rem::LEN
+    }
+
+    /// Supplies all remaining arguments at once and calls the function.
+    ///
+    /// This is useful when some arguments are supplied one by one and the
+    /// rest is available as a tuple.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::curry;
+    ///
+    /// let fun = |a: i32, b: String, c: char| format!("{}{}{}", a, b, c);
+    /// let partial = curry(fun)(1);
+    ///
+    /// assert_eq!(partial.complete((String::from("-"), 'a')), "1-a");
+    /// ```
+    #[inline]
+    pub fn complete(self, rest: Rem) -> <Self as CallCurried<Rem>>::Output
+    where
+        Self: CallCurried<Rem>,
+    {
+        self.call_curried(rest)
+    }
 }
 
 impl<S, F, Rem> FnOnce<(Rem::Take,)> for Curry<S, F, Rem>
@@ -182,9 +227,164 @@ where
 {
 }
 
+impl<T, F, R> PartialEq for Curry<T, F, R>
+where
+    T: PartialEq,
+    F: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.supplied == other.supplied && self.f == other.f }
+}
+
+impl<T, F, R> Eq for Curry<T, F, R>
+where
+    T: Eq,
+    F: Eq,
+{
+}
+
+impl<T, F, R> Hash for Curry<T, F, R>
+where
+    T: Hash,
+    F: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.supplied.hash(state);
+        self.f.hash(state);
+    }
+}
+
+/// Curring of the first `N` arguments.
+///
+/// Returns function that takes the first `N` arguments one by one and then
+/// the rest of them (possibly none) at once, e.g. function `Fn(A, B, C, D) ->
+/// R` curried with `N == 2` becomes `Fn(A) -> Fn(B) -> Fn(C, D) -> R`.
+///
+/// Implemented for `1 <= N <= 8`.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::curry_n;
+///
+/// let fun = |a: i32, b: i32, c: i32| a * 100 + b * 10 + c;
+/// let fun = curry_n::<1, _, _>(fun);
+/// let res = fun(1)(2, 3);
+/// assert_eq!(res, 123);
+/// ```
+///
+/// See also:
+/// - [`curry`]
+/// - extension on all functions: [`FnExt::curry_n`]
+///
+/// [`FnExt::curry_n`]: crate::unstable::FnExt::curry_n
+#[inline]
+pub fn curry_n<const N: usize, F, Args>(f: F) -> CurryN<F, Args, N>
+where
+    F: FnOnce<Args>,
+{
+    CurryN::new(f)
+}
+
+/// Represents function `F` with the first `N` arguments curried.
+///
+/// For documentation see [`curry_n`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct CurryN<F, Args, const N: usize> {
+    f: F,
+    marker: PhantomData<fn(Args)>,
+}
+
+impl<F, Args, const N: usize> CurryN<F, Args, N> {
+    /// Creates function `f` with the first `N` arguments curried.
+    ///
+    /// It's preferred to use [`curry_n`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self
+    where
+        F: FnOnce<Args>,
+    {
+        CurryN {
+            f,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns inner function (with already supplied arguments).
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function (with already supplied
+    /// arguments).
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+// The last curried argument, the rest are taken at once
+impl<F, Args> FnOnce<(Args::Take,)> for CurryN<F, Args, 1>
+where
+    F: FnOnce<Args>,
+    Args: TupleTake,
+{
+    type Output = Supply<Args::Take, F, Args>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (arg,): (Args::Take,)) -> Self::Output {
+        Supply::new(self.f, arg)
+    }
+}
+
+macro_rules! curry_n_impl {
+    ($( $n:literal => $m:literal ),+) => {
+        $(
+            impl<F, Args> FnOnce<(Args::Take,)> for CurryN<F, Args, $n>
+            where
+                F: FnOnce<Args>,
+                Args: TupleTake,
+            {
+                type Output = CurryN<Supply<Args::Take, F, Args>, Args::Rem, $m>;
+
+                #[inline]
+                extern "rust-call" fn call_once(self, (arg,): (Args::Take,)) -> Self::Output {
+                    CurryN {
+                        f: Supply::new(self.f, arg),
+                        marker: PhantomData,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+curry_n_impl!(2 => 1, 3 => 2, 4 => 3, 5 => 4, 6 => 5, 7 => 6, 8 => 7);
+
+impl<F, Args, const N: usize> Debug for CurryN<F, Args, N>
+where
+    F: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("CurryN").field("f", &self.f).finish()
+    }
+}
+
+impl<F, Args, const N: usize> Clone for CurryN<F, Args, N>
+where
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        CurryN {
+            f: self.f.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F, Args, const N: usize> Copy for CurryN<F, Args, N> where F: Copy {}
+
 #[cfg(test)]
 mod tests {
-    use crate::unstable::curry::Curry;
+    use crate::unstable::{curry::Curry, curry_n};
 
     #[test]
     fn one_fn() {
@@ -230,4 +430,75 @@ mod tests {
 
         assert_eq!(val, "12O_ohell(o)4");
     }
+
+    #[test]
+    fn clone_partially_applied() {
+        let fun = |a: i32, b: String, c: char| format!("{}{}{}", a, b, c);
+        let partial = Curry::new(fun)(1)(String::from("-"));
+        let clone = partial.clone();
+
+        assert_eq!(partial('a'), "1-a");
+        assert_eq!(clone('b'), "1-b");
+    }
+
+    #[test]
+    fn copy_partially_applied() {
+        let fun = |a: i32, b: i32, c: i32| a * 100 + b * 10 + c;
+        let partial = Curry::new(fun)(1)(2);
+        let copy = partial;
+
+        assert_eq!(partial(3), 123);
+        assert_eq!(copy(4), 124);
+    }
+
+    #[test]
+    fn curry_first_n() {
+        let fun = |a: i32, b: String, c: char, d: u8| format!("{}{}{}{}", a, b, c, d);
+
+        assert_eq!(
+            curry_n::<1, _, _>(fun)(1)(String::from("-"), 'a', 2),
+            "1-a2"
+        );
+        assert_eq!(
+            curry_n::<2, _, _>(fun)(1)(String::from("-"))('a', 2),
+            "1-a2"
+        );
+        assert_eq!(
+            curry_n::<3, _, _>(fun)(1)(String::from("-"))('a')(2),
+            "1-a2"
+        );
+        // all arguments are curried, nothing is left for the final call
+        assert_eq!(
+            curry_n::<4, _, _>(fun)(1)(String::from("-"))('a')(2)(),
+            "1-a2"
+        );
+    }
+
+    #[test]
+    fn complete() {
+        let fun = |a: i32, b: i32, c: i32| a * 100 + b * 10 + c;
+
+        assert_eq!(Curry::new(fun).complete((1, 2, 3)), 123);
+        assert_eq!(Curry::new(fun)(1)(2).complete((3,)), 123);
+        assert_eq!(Curry::new(fun)(1).remaining(), 2);
+    }
+
+    #[test]
+    fn eq() {
+        let fun: fn(i32, i32) -> i32 = |a, b| a + b;
+
+        assert_eq!(Curry::new(fun)(1), Curry::new(fun)(1));
+        assert_ne!(Curry::new(fun)(1), Curry::new(fun)(2));
+    }
+
+    #[test]
+    fn debug() {
+        let fun: fn(i32, i32) -> i32 = |a, b| a + b;
+        let partial = Curry::new(fun)(1);
+
+        assert_eq!(
+            format!("{:?}", partial),
+            format!("Curry {{ supplied: (1,), f: {:?} }}", fun)
+        );
+    }
 }
@@ -0,0 +1,148 @@
+use crate::tuple::{pop::TuplePop, push::TuplePush};
+
+/// Supply default for the last argument of a function.
+///
+/// Takes function `f` and function `default` and returns function that takes
+/// all arguments of `f` but the last one, and calls `default` to produce the
+/// last one (in other words something _like_ `|a: A| f(a, default())`).
+///
+/// Unlike [`supply`] which stores a value, the default is produced lazily on
+/// every call.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::with_default_arg;
+///
+/// let add = |a: i32, b: i32| a + b;
+/// let add = with_default_arg(add, || 10);
+///
+/// assert_eq!(add(1), 11);
+/// assert_eq!(add(32), 42);
+/// ```
+///
+/// ```
+/// use fntools::unstable::with_default_arg;
+///
+/// let item = |name: &str, price: u32, tags: Vec<&str>| format!("{} ${} {:?}", name, price, tags);
+/// let item = with_default_arg(item, Vec::new);
+///
+/// assert_eq!(item("apple", 3), "apple $3 []");
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::with_default_arg`]
+///
+/// [`supply`]: crate::unstable::supply
+/// [`FnExt::with_default_arg`]: crate::unstable::FnExt::with_default_arg
+#[inline]
+pub fn with_default_arg<A, F, D>(f: F, default: D) -> WithDefaultArg<F, D>
+where
+    F: FnOnce<A>,
+    A: TuplePop,
+    D: FnOnce() -> A::Pop,
+{
+    WithDefaultArg::new(f, default)
+}
+
+/// Represents function `F` with default for the last argument produced by
+/// `D`.
+///
+/// For documentation see [`with_default_arg`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WithDefaultArg<F, D> {
+    f: F,
+    default: D,
+}
+
+impl<F, D> WithDefaultArg<F, D> {
+    /// Creates version of the function `f` with default for the last argument
+    /// produced by `default`.
+    ///
+    /// It's preferred to use [`with_default_arg`] instead.
+    #[inline]
+    pub fn new<A>(f: F, default: D) -> Self
+    where
+        F: FnOnce<A>,
+        A: TuplePop,
+        D: FnOnce() -> A::Pop,
+    {
+        WithDefaultArg { f, default }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, D) {
+        let WithDefaultArg { f, default } = self;
+        (f, default)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &D) {
+        let WithDefaultArg { f, default } = self;
+        (f, default)
+    }
+}
+
+impl<A, F, D> FnOnce<A> for WithDefaultArg<F, D>
+where
+    D: FnOnce<()>,
+    A: TuplePush<D::Output>,
+    F: FnOnce<A::Res>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let WithDefaultArg { f, default } = self;
+        f.call_once(args.push(default()))
+    }
+}
+
+impl<A, F, D> FnMut<A> for WithDefaultArg<F, D>
+where
+    D: FnMut<()>,
+    A: TuplePush<D::Output>,
+    F: FnMut<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let WithDefaultArg { f, default } = self;
+        f.call_mut(args.push(default()))
+    }
+}
+
+impl<A, F, D> Fn<A> for WithDefaultArg<F, D>
+where
+    D: Fn<()>,
+    A: TuplePush<D::Output>,
+    F: Fn<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let WithDefaultArg { f, default } = self;
+        f.call(args.push(default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::unstable::FnExt;
+
+    #[test]
+    fn lazy_default() {
+        let calls = Cell::new(0);
+        let default = || {
+            calls.set(calls.get() + 1);
+            calls.get()
+        };
+        let fun = (|a: &str, b: i32| format!("{}{}", a, b)).with_default_arg(default);
+
+        assert_eq!(calls.get(), 0);
+        assert_eq!(fun("a"), "a1");
+        assert_eq!(fun("b"), "b2");
+    }
+}
@@ -0,0 +1,111 @@
+/// Run function for side effects only, returning its input.
+///
+/// Takes function `f` and returns function that calls `f` with a clone of
+/// its argument, discards the output and returns the argument itself (in
+/// other words something _like_ `|a: A| { f(a.clone()); a }`).
+///
+/// This is different from [`unit`] which returns `()` instead of the output,
+/// while `discard` threads the input through, so side-effecting stages can
+/// be put in the middle of a value-preserving pipeline.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::{discard, unit, FnExt};
+/// use std::cell::RefCell;
+///
+/// let log = RefCell::new(Vec::new());
+/// let record = |s: String| log.borrow_mut().push(s);
+///
+/// let fun = (|s: &str| s.to_uppercase())
+///     .chain(discard(record))
+///     .chain(|s: String| s + "!");
+///
+/// assert_eq!(fun("hi"), "HI!");
+/// assert_eq!(*log.borrow(), ["HI"]);
+///
+/// // `unit` on the other hand loses the value
+/// let _: () = unit(record)(String::from("lost"));
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::discard`]
+///
+/// [`unit`]: crate::unstable::unit()
+/// [`FnExt::discard`]: crate::unstable::FnExt::discard
+#[inline]
+pub fn discard<A, F>(f: F) -> Discard<F>
+where
+    F: FnOnce<(A,)>,
+    A: Clone,
+{
+    Discard::new(f)
+}
+
+/// Represents function `F` called only for side effects.
+///
+/// For documentation see [`discard`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Discard<F> {
+    f: F,
+}
+
+impl<F> Discard<F> {
+    /// Creates version of the function `f` which returns its input.
+    ///
+    /// It's preferred to use [`discard`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<(A,)>,
+        A: Clone,
+    {
+        Discard { f }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<A, F> FnOnce<(A,)> for Discard<F>
+where
+    F: FnOnce<(A,)>,
+    A: Clone,
+{
+    type Output = A;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+        self.f.call_once((a.clone(),));
+        a
+    }
+}
+
+impl<A, F> FnMut<(A,)> for Discard<F>
+where
+    F: FnMut<(A,)>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+        self.f.call_mut((a.clone(),));
+        a
+    }
+}
+
+impl<A, F> Fn<(A,)> for Discard<F>
+where
+    F: Fn<(A,)>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+        self.f.call((a.clone(),));
+        a
+    }
+}
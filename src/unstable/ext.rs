@@ -1,33 +1,132 @@
+#[cfg(feature = "std")]
+use core::hash::Hash;
+
+#[cfg(feature = "service")]
+use crate::service::FnService;
+#[cfg(feature = "parallel")]
+use crate::unstable::fanout::{par_fanout, ParFanout};
+#[cfg(feature = "alloc")]
+use crate::unstable::{
+    boxed::DynFn,
+    counted::{counted, Counted},
+};
+#[cfg(feature = "std")]
+use crate::unstable::{
+    cache::{cache_n, memoize, CacheN, Memoize},
+    timed::{timed, Timed},
+};
 use crate::{
-    tuple::{flip::FlipTuple, take::TupleTake},
+    fallible::{first_ok, FirstOk},
+    tuple::{
+        flatten::FlattenTuple, flip::FlipTuple, map::TupleMap, pop::TuplePop, take::TupleTake,
+        take_last::TupleTakeLast,
+    },
     unstable::{
+        assert_output::{debug_assert_output, DebugAssertOutput},
+        cache_last::{cache_last, CacheLast},
         chain::{chain, Chain},
+        composable::{composable, Composable},
         compose::{compose, Compose},
-        curry::{curry, Curry},
+        constant::{constant, Const},
+        curry::{curry, curry_n, Curry, CurryN},
+        default_arg::{with_default_arg, WithDefaultArg},
+        discard::{discard, Discard},
+        fanout::{converge, fanout, Converge, Fanout},
+        filter_output::{filter_output, FilterOutput},
+        fix::{fix, Fix},
+        flatten_result::{flatten_result, FlattenResult},
         flip::{flip, Flip},
-        supply::{supply, Supply},
+        map_err::{map_err, MapErr},
+        map_tuple_output::{map_tuple_output, MapTupleOutput},
+        retry::{retry, retry_with, Retry, RetryWith},
+        supply::{supply, supply_right, Supply, SupplyRight},
+        tap::{inspect_args, tap, InspectArgs, Tap},
+        times::{times, Times},
+        uncurry::{uncurry, Uncurry},
         unit::{unit, Unit},
-        untuple::{untuple, Untuple},
+        untuple::{untuple, untuple_flat, Untuple, UntupleFlat},
     },
 };
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use core::cell::Cell;
 
 /// Extensions for Fn* types.
 ///
 /// Provide shortcuts for
 /// - [`chain`]
+/// - [`chain`] with unary function (`map_output`)
+/// - [`map_tuple_output`]
 /// - [`chain`] + [`untuple`]
+/// - [`chain`] + [`untuple_flat`]
 /// - [`compose`]
 /// - [`compose`] + [`untuple`]
+/// - [`composable`]
 /// - [`supply`]
+/// - [`supply_right`]
+/// - [`with_default_arg`]
 /// - [`flip`]
 /// - [`curry`]
+/// - [`curry_n`]
+/// - [`uncurry`]
+/// - [`map_err`]
+/// - [`flatten_result`]
+/// - [`first_ok`]
+/// - [`retry`]
+/// - [`retry_with`]
+/// - [`tap`]
+/// - [`inspect_args`]
+/// - [`debug_assert_output`]
+/// - [`filter_output`]
+/// - [`times`]
+/// - [`discard`]
+/// - [`fanout`]
+/// - [`converge`]
+/// - [`cache_last`]
+/// - [`fix`]
+/// - [`DynFn`] (under `#[cfg(feature = "alloc")]`)
+/// - [`counted`] (under `#[cfg(feature = "alloc")]`)
+/// - [`cache_n`] (under `#[cfg(feature = "std")]`)
+/// - [`memoize`] (under `#[cfg(feature = "std")]`)
+/// - [`timed`] (under `#[cfg(feature = "std")]`)
+/// - `par_fanout` (under `#[cfg(feature = "parallel")]`)
+/// - [`FnService`] (under `#[cfg(feature = "service")]`)
 ///
 /// [`chain`]: crate::unstable::chain
+/// [`map_tuple_output`]: crate::unstable::map_tuple_output
 /// [`untuple`]: crate::unstable::untuple
+/// [`untuple_flat`]: crate::unstable::untuple_flat
 /// [`compose`]: crate::unstable::compose
+/// [`composable`]: crate::unstable::composable
 /// [`supply`]: crate::unstable::supply
+/// [`supply_right`]: crate::unstable::supply_right
+/// [`with_default_arg`]: crate::unstable::with_default_arg
 /// [`flip`]: crate::unstable::flip
 /// [`curry`]: crate::unstable::curry
+/// [`curry_n`]: crate::unstable::curry_n
+/// [`uncurry`]: crate::unstable::uncurry
+/// [`map_err`]: crate::unstable::map_err
+/// [`flatten_result`]: crate::unstable::flatten_result
+/// [`first_ok`]: crate::unstable::first_ok
+/// [`retry`]: crate::unstable::retry
+/// [`retry_with`]: crate::unstable::retry_with
+/// [`tap`]: crate::unstable::tap
+/// [`inspect_args`]: crate::unstable::inspect_args
+/// [`debug_assert_output`]: crate::unstable::debug_assert_output
+/// [`filter_output`]: crate::unstable::filter_output
+/// [`times`]: crate::unstable::times
+/// [`discard`]: crate::unstable::discard
+/// [`fanout`]: crate::unstable::fanout
+/// [`converge`]: crate::unstable::converge
+/// [`cache_last`]: crate::unstable::cache_last
+/// [`fix`]: crate::unstable::fix
+/// [`DynFn`]: crate::unstable::DynFn
+/// [`counted`]: crate::unstable::counted
+/// [`cache_n`]: crate::unstable::cache_n
+/// [`memoize`]: crate::unstable::memoize
+/// [`timed`]: crate::unstable::timed
+/// [`FnService`]: crate::service::FnService
 pub trait FnExt<Args>: Sized {
     /// Chain two functions (`g ∘ self`)
     ///
@@ -57,6 +156,61 @@ pub trait FnExt<Args>: Sized {
         chain(self, g)
     }
 
+    /// Map output of `self` with unary function `m` (`m ∘ self`).
+    ///
+    /// This is the same as [`chain`](FnExt::chain), but `m` is required to
+    /// be an ordinary unary function of the output, which gives clearer type
+    /// errors in the simple case.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let area = |w: u32, h: u32| w * h;
+    /// let describe = area.map_output(|a: u32| format!("{} m²", a));
+    ///
+    /// assert_eq!(describe(3, 4), "12 m²");
+    /// ```
+    ///
+    /// For more info see [`chain`]
+    ///
+    /// [`chain`]: crate::unstable::chain
+    #[inline]
+    fn map_output<R, M>(self, m: M) -> Chain<Self, M>
+    where
+        Self: FnOnce<Args>,
+        M: FnOnce(Self::Output) -> R,
+    {
+        chain(self, m)
+    }
+
+    /// Map every element of the homogeneous tuple output of `self` with `m`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let neighbours = |x: i32| (x - 1, x, x + 1);
+    /// let double = |x: i32| x * 2;
+    /// let doubled = neighbours.map_tuple_output(double);
+    ///
+    /// assert_eq!(doubled(1), (0, 2, 4));
+    /// assert_eq!(doubled(5), (8, 10, 12));
+    /// ```
+    ///
+    /// For more info see [`map_tuple_output`]
+    ///
+    /// [`map_tuple_output`]: crate::unstable::map_tuple_output
+    #[inline]
+    fn map_tuple_output<M>(self, m: M) -> MapTupleOutput<Self, M>
+    where
+        Self: FnOnce<Args>,
+        Self::Output: TupleMap,
+        M: FnMut<(<Self::Output as TupleMap>::Item,)>,
+    {
+        map_tuple_output(self, m)
+    }
+
     /// Chain two functions (`g ∘ self`) **u**n**t**upling result of the first
     /// (`self`).
     ///
@@ -79,6 +233,32 @@ pub trait FnExt<Args>: Sized {
         self.chain(untuple(g))
     }
 
+    /// Chain two functions (`g ∘ self`) flattening (see [`FlattenTuple`])
+    /// nested tuple returned by the first (`self`) into arguments of the
+    /// second.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let split = |a: i32| ((a / 100, a / 10 % 10), a % 10);
+    /// let digits = split.chain_flat(|a, b, c| [a, b, c]);
+    /// //                             ^^^^^^^---- note: no destructing (`|((a, b), c)|`)
+    ///
+    /// assert_eq!(digits(123), [1, 2, 3]);
+    /// ```
+    ///
+    /// [`FlattenTuple`]: crate::tuple::flatten::FlattenTuple
+    #[inline]
+    fn chain_flat<G, Shape>(self, g: G) -> Chain<Self, UntupleFlat<G, Shape>>
+    where
+        Self: FnOnce<Args>,
+        Self::Output: FlattenTuple<Shape>,
+        G: FnOnce<<Self::Output as FlattenTuple<Shape>>::Res>,
+    {
+        self.chain(untuple_flat(g))
+    }
+
     /// Compose two functions (`self ∘ g`)
     ///
     /// # Examples:
@@ -147,6 +327,93 @@ pub trait FnExt<Args>: Sized {
         compose(untuple(self), g)
     }
 
+    /// Enable `>>` ([`chain`](FnExt::chain)) and `<<`
+    /// ([`compose`](FnExt::compose)) operators on `self`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let add_two = |a: i32| a + 2;
+    /// let add_three = |a: i32| a + 3;
+    /// let add_eight = add_two.composable() >> add_three >> add_three;
+    ///
+    /// assert_eq!(add_eight(4), 12);
+    /// ```
+    ///
+    /// For more info see [`composable`]
+    ///
+    /// [`composable`]: crate::unstable::composable
+    #[inline]
+    fn composable(self) -> Composable<Self>
+    where
+        Self: FnOnce<Args>,
+    {
+        composable(self)
+    }
+
+    /// Call `self` with a tuple of arguments.
+    ///
+    /// This is useful when arguments are assembled as a tuple (e.g. by
+    /// [`product`]).
+    ///
+    /// ## Example
+    /// ```
+    /// use fntools::{unstable::FnExt, value::Apply};
+    ///
+    /// let add = |a: i32, b: i32| a + b;
+    ///
+    /// assert_eq!(add.call_with((2, 3)), 5);
+    /// // doesn't clash with `Apply::apply`
+    /// assert_eq!(5.apply(|x: i32| x * 2), 10);
+    /// ```
+    ///
+    /// For more info see [`spread`]
+    ///
+    /// [`product`]: crate::product
+    /// [`spread`]: crate::unstable::spread
+    #[inline]
+    fn call_with(self, args: Args) -> Self::Output
+    where
+        Self: FnOnce<Args>,
+    {
+        self.call_once(args)
+    }
+
+    /// Call `self` with `args` right away and return function that always
+    /// returns (clones of) the result.
+    ///
+    /// Unlike [`supply`](FnExt::supply) which defers the call, this computes
+    /// the result eagerly, "freezing" the computation.
+    ///
+    /// ## Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let expensive = |x: u64| {
+    ///     calls.set(calls.get() + 1);
+    ///     (1..=x).product::<u64>()
+    /// };
+    /// let frozen = expensive.to_constant((3,));
+    ///
+    /// assert_eq!(frozen(), 6);
+    /// assert_eq!(frozen(), 6);
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    ///
+    /// For more info see [`constant`]
+    ///
+    /// [`constant`]: crate::unstable::constant
+    #[inline]
+    fn to_constant(self, args: Args) -> Const<Self::Output>
+    where
+        Self: FnOnce<Args>,
+    {
+        constant(self.call_once(args))
+    }
+
     /// Supply argument to function.
     ///
     /// ## Example
@@ -171,6 +438,59 @@ pub trait FnExt<Args>: Sized {
         supply(self, argument)
     }
 
+    /// Supply the last argument to function.
+    ///
+    /// ## Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let div = |num: f64, den: f64| num / den;
+    /// let half = div.supply_right(2.0);
+    ///
+    /// assert_eq!(half(5.0), 2.5);
+    /// ```
+    ///
+    /// For more info see [`supply_right`]
+    ///
+    /// [`supply_right`]: crate::unstable::supply_right
+    #[inline]
+    fn supply_right(
+        self,
+        argument: <Args as TupleTakeLast>::Take,
+    ) -> SupplyRight<<Args as TupleTakeLast>::Take, Self, Args>
+    where
+        Self: FnOnce<Args>,
+        Args: TupleTakeLast,
+    {
+        supply_right(self, argument)
+    }
+
+    /// Supply default for the last argument of `self` produced by `default`
+    /// on every call.
+    ///
+    /// # Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let add = |a: i32, b: i32| a + b;
+    /// let add = add.with_default_arg(|| 10);
+    ///
+    /// assert_eq!(add(5), 15);
+    /// ```
+    ///
+    /// For more info see [`with_default_arg`]
+    ///
+    /// [`with_default_arg`]: crate::unstable::with_default_arg
+    #[inline]
+    fn with_default_arg<D>(self, default: D) -> WithDefaultArg<Self, D>
+    where
+        Self: FnOnce<Args>,
+        Args: TuplePop,
+        D: FnOnce() -> Args::Pop,
+    {
+        with_default_arg(self, default)
+    }
+
     /// Flips argument order of `self`.
     ///
     /// # Example
@@ -210,6 +530,55 @@ pub trait FnExt<Args>: Sized {
         curry(self)
     }
 
+    /// Curring of the first `N` arguments, the rest are taken at once.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = |a: i32, b: i32, c: i32| a * 100 + b * 10 + c;
+    /// let fun = fun.curry_n::<1>();
+    /// let res = fun(1)(2, 3);
+    /// assert_eq!(res, 123);
+    /// ```
+    ///
+    /// For more info see [`curry_n`]
+    ///
+    /// [`curry_n`]: crate::unstable::curry_n
+    #[inline]
+    fn curry_n<const N: usize>(self) -> CurryN<Self, Args, N>
+    where
+        Self: FnOnce<Args>,
+    {
+        curry_n(self)
+    }
+
+    /// Uncurring, the inverse of [`curry`](FnExt::curry).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::ops::Add;
+    ///
+    /// let fun = i32::add.curry().uncurry();
+    /// assert_eq!(fun(2, 3), 5);
+    ///
+    /// // uncurried function is a proper function of two arguments
+    /// fn apply_binary(f: impl FnOnce(i32, i32) -> i32) -> i32 { f(2, 3) }
+    /// assert_eq!(apply_binary(i32::add.curry().uncurry()), 5);
+    /// ```
+    ///
+    /// For more info see [`uncurry`]
+    ///
+    /// [`uncurry`]: crate::unstable::uncurry
+    #[inline]
+    fn uncurry(self) -> Uncurry<Self>
+    where
+        Self: FnOnce<Args>,
+    {
+        uncurry(self)
+    }
+
     /// Unit function output
     ///
     /// ## Examples
@@ -228,6 +597,704 @@ pub trait FnExt<Args>: Sized {
     {
         unit(self)
     }
+
+    /// Map error of fallible function.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::num::ParseIntError;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyError(String);
+    ///
+    /// let parse = str::parse::<u8>.map_err(|e: ParseIntError| MyError(e.to_string()));
+    ///
+    /// assert_eq!(parse("3"), Ok(3));
+    /// assert_eq!(
+    ///     parse("256"),
+    ///     Err(MyError(String::from(
+    ///         "number too large to fit in target type"
+    ///     )))
+    /// );
+    /// ```
+    ///
+    /// For more info see [`map_err`]
+    ///
+    /// [`map_err`]: crate::unstable::map_err
+    #[inline]
+    fn map_err<T, E, M>(self, m: M) -> MapErr<Self, M>
+    where
+        Self: FnOnce<Args, Output = Result<T, E>>,
+        M: FnOnce<(E,)>,
+    {
+        map_err(self, m)
+    }
+
+    /// Flatten nested `Result<Result<T, E>, E>` output of `self` into
+    /// `Result<T, E>`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::convert::TryFrom;
+    ///
+    /// let checked_div = |a: i32, b: i32| a.checked_div(b).ok_or("division by zero");
+    /// let to_u8 = |q: i32| u8::try_from(q).map_err(|_| "overflow");
+    /// let fun = (move |a: i32, b: i32| checked_div(a, b).map(to_u8)).flatten_result();
+    ///
+    /// assert_eq!(fun(10, 2), Ok(5));
+    /// assert_eq!(fun(1, 0), Err("division by zero"));
+    /// assert_eq!(fun(1000, 2), Err("overflow"));
+    /// ```
+    ///
+    /// For more info see [`flatten_result`]
+    ///
+    /// [`flatten_result`]: crate::unstable::flatten_result
+    #[inline]
+    fn flatten_result<T, E>(self) -> FlattenResult<Self>
+    where
+        Self: FnOnce<Args, Output = Result<Result<T, E>, E>>,
+    {
+        flatten_result(self)
+    }
+
+    /// Try `self` and, if it fails, try `g` with the same arguments.
+    ///
+    /// Chaining several `.first_ok(...)`s builds a fallback cascade.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let parse = str::parse::<u8>
+    ///     .first_ok(|s: &str| s.trim().parse())
+    ///     .first_ok(|s: &str| s.trim().trim_start_matches('+').parse());
+    ///
+    /// assert_eq!(parse("1"), Ok(1));
+    /// assert_eq!(parse(" 2 "), Ok(2));
+    /// assert_eq!(parse(" +3"), Ok(3));
+    /// assert!(parse("-4").is_err());
+    /// ```
+    ///
+    /// For more info see [`first_ok`]
+    ///
+    /// [`first_ok`]: crate::unstable::first_ok
+    #[inline]
+    fn first_ok<T, E, G>(self, g: G) -> FirstOk<Self, G>
+    where
+        Self: FnOnce<Args, Output = Result<T, E>>,
+        G: FnOnce<Args, Output = Result<T, E>>,
+        Args: Clone,
+    {
+        first_ok(self, g)
+    }
+
+    /// Retry `self` up to `attempts` times until it returns `Ok`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let flaky = |s: &str| {
+    ///     calls.set(calls.get() + 1);
+    ///     s[calls.get()..].parse::<u8>()
+    /// };
+    /// let parse = flaky.retry(3);
+    ///
+    /// assert_eq!(parse("xx12"), Ok(12));
+    /// assert_eq!(calls.get(), 2);
+    /// ```
+    ///
+    /// For more info see [`retry`]
+    ///
+    /// [`retry`]: crate::unstable::retry
+    #[inline]
+    fn retry<T, E>(self, attempts: usize) -> Retry<Self>
+    where
+        Self: FnMut<Args, Output = Result<T, E>>,
+        Args: Clone,
+    {
+        retry(self, attempts)
+    }
+
+    /// Retry `self` up to `attempts` times, calling `on_retry` with the number
+    /// of the failed attempt and the error between attempts.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::{Cell, RefCell};
+    ///
+    /// let calls = Cell::new(0);
+    /// let flaky = |()| {
+    ///     calls.set(calls.get() + 1);
+    ///     if calls.get() <= 2 {
+    ///         Err("timeout")
+    ///     } else {
+    ///         Ok("done")
+    ///     }
+    /// };
+    /// let attempts = RefCell::new(Vec::new());
+    /// let record = |n: usize, _: &&str| attempts.borrow_mut().push(n);
+    /// let fun = flaky.retry_with(3, record);
+    ///
+    /// assert_eq!(fun(()), Ok("done"));
+    /// assert_eq!(*attempts.borrow(), [1, 2]);
+    /// ```
+    ///
+    /// For more info see [`retry_with`]
+    ///
+    /// [`retry_with`]: crate::unstable::retry_with
+    #[inline]
+    fn retry_with<T, E, H>(self, attempts: usize, on_retry: H) -> RetryWith<Self, H>
+    where
+        Self: FnMut<Args, Output = Result<T, E>>,
+        H: FnMut(usize, &E),
+        Args: Clone,
+    {
+        retry_with(self, attempts, on_retry)
+    }
+
+    /// Inspect output of `self` by reference, returning it unchanged.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::RefCell;
+    ///
+    /// let log = RefCell::new(Vec::new());
+    /// let mul = |a: i32, b: i32| a * b;
+    /// let record = |out: &i32| log.borrow_mut().push(*out);
+    /// let mul = mul.tap(record);
+    ///
+    /// assert_eq!(mul(2, 3), 6);
+    /// assert_eq!(mul(4, 5), 20);
+    /// assert_eq!(*log.borrow(), [6, 20]);
+    /// ```
+    ///
+    /// For more info see [`tap`]
+    ///
+    /// [`tap`]: crate::unstable::tap
+    #[inline]
+    fn tap<T>(self, t: T) -> Tap<Self, T>
+    where
+        Self: FnOnce<Args>,
+        T: FnOnce(&Self::Output),
+    {
+        tap(self, t)
+    }
+
+    /// Inspect arguments of `self` by reference before calling it.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::RefCell;
+    ///
+    /// let log = RefCell::new(Vec::new());
+    /// let greet = |name: &str| format!("Hello, {}!", name);
+    /// let record = |&(name,): &(&str,)| log.borrow_mut().push(name.len());
+    /// let greet = greet.inspect_args(record);
+    ///
+    /// assert_eq!(greet("world"), "Hello, world!");
+    /// assert_eq!(greet("fntools"), "Hello, fntools!");
+    /// assert_eq!(*log.borrow(), [5, 7]);
+    /// ```
+    ///
+    /// For more info see [`inspect_args`]
+    ///
+    /// [`inspect_args`]: crate::unstable::inspect_args
+    #[inline]
+    fn inspect_args<I>(self, i: I) -> InspectArgs<Self, I>
+    where
+        Self: FnOnce<Args>,
+        I: FnOnce(&Args),
+    {
+        inspect_args(self, i)
+    }
+
+    /// Check output of `self` with predicate `p` in debug builds, returning
+    /// the output unchanged.
+    ///
+    /// In release builds `p` is never called.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let len = |s: &str| s.len();
+    /// let len = len.debug_assert_output(|out: &usize| *out <= 5);
+    ///
+    /// assert_eq!(len("hello"), 5);
+    /// ```
+    ///
+    /// For more info see [`debug_assert_output`]
+    ///
+    /// [`debug_assert_output`]: crate::unstable::debug_assert_output
+    #[inline]
+    fn debug_assert_output<P>(self, p: P) -> DebugAssertOutput<Self, P>
+    where
+        Self: FnOnce<Args>,
+        P: FnOnce(&Self::Output) -> bool,
+    {
+        debug_assert_output(self, p)
+    }
+
+    /// Filter output of `self` with predicate `p`, returning `Some(output)`
+    /// if `p` holds and `None` otherwise.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let area_left = |w: i32, h: i32| 100 - w * h;
+    /// let positive = |out: &i32| *out > 0;
+    /// let fun = area_left.filter_output(positive);
+    ///
+    /// assert_eq!(fun(5, 4), Some(80));
+    /// assert_eq!(fun(10, 10), None);
+    /// assert_eq!(fun(20, 10), None);
+    /// ```
+    ///
+    /// For more info see [`filter_output`]
+    ///
+    /// [`filter_output`]: crate::unstable::filter_output
+    #[inline]
+    fn filter_output<P>(self, p: P) -> FilterOutput<Self, P>
+    where
+        Self: FnOnce<Args>,
+        P: FnOnce(&Self::Output) -> bool,
+    {
+        filter_output(self, p)
+    }
+
+    /// Repeat `self` `n` times (`self.times(0)` is the identity function).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let double = |x: u64| x * 2;
+    ///
+    /// assert_eq!(double.times(3)(1), 8);
+    /// assert_eq!(double.times(0)(1), 1);
+    /// ```
+    ///
+    /// For more info see [`times`]
+    ///
+    /// [`times`]: crate::unstable::times
+    #[inline]
+    fn times<A>(self, n: usize) -> Times<Self>
+    where
+        Self: FnMut(A) -> A,
+    {
+        times(self, n)
+    }
+
+    /// Call `self` for side effects only, returning its (cloned) argument
+    /// instead of the output.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::Cell;
+    ///
+    /// let total = Cell::new(0);
+    /// let add_to_total = |x: i32| total.set(total.get() + x);
+    /// let fun = add_to_total.discard().chain(|x: i32| x * 2);
+    ///
+    /// assert_eq!(fun(5), 10);
+    /// assert_eq!(fun(1), 2);
+    /// assert_eq!(total.get(), 6);
+    /// ```
+    ///
+    /// For more info see [`discard`]
+    ///
+    /// [`discard`]: crate::unstable::discard
+    #[inline]
+    fn discard<A>(self) -> Discard<Self>
+    where
+        Self: FnOnce<(A,)>,
+        A: Clone,
+    {
+        discard(self)
+    }
+
+    /// Call `self` and `g` with the same (cloned) arguments, returning both
+    /// outputs.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let stats = str::len.fanout(str::is_empty);
+    ///
+    /// assert_eq!(stats("fntools"), (7, false));
+    /// assert_eq!(stats(""), (0, true));
+    /// ```
+    ///
+    /// For more info see [`fanout`]
+    ///
+    /// [`fanout`]: crate::unstable::fanout
+    #[inline]
+    fn fanout<G>(self, g: G) -> Fanout<Self, G>
+    where
+        Self: FnOnce<Args>,
+        G: FnOnce<Args>,
+        Args: Clone,
+    {
+        fanout(self, g)
+    }
+
+    /// Call `self` and `g` with the same (cloned) arguments in parallel,
+    /// returning both outputs.
+    ///
+    /// Arguments must be `Clone + Send` and outputs must be `Send`.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "parallel")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let count_primes = |n: u32| (2..n).filter(|i| (2..*i).all(|d| i % d != 0)).count();
+    /// let count_squares = |n: u32| (1..).take_while(|i| i * i < n).count();
+    /// let stats = count_primes.par_fanout(count_squares);
+    ///
+    /// assert_eq!(stats(50), (15, 7));
+    /// ```
+    ///
+    /// For more info see [`par_fanout`]
+    ///
+    /// [`par_fanout`]: crate::unstable::par_fanout
+    #[cfg(feature = "parallel")]
+    #[inline]
+    fn par_fanout<G>(self, g: G) -> ParFanout<Self, G>
+    where
+        Self: FnOnce<Args> + Send,
+        G: FnOnce<Args> + Send,
+        Self::Output: Send,
+        G::Output: Send,
+        Args: Clone + Send,
+    {
+        par_fanout(self, g)
+    }
+
+    /// Call `self` and `g` with the same (cloned) arguments and combine both
+    /// outputs with `h`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let sum = |v: &[u32]| v.iter().sum::<u32>();
+    /// let len = |v: &[u32]| v.len() as u32;
+    /// let average = sum.converge(len, |s, n| s / n);
+    ///
+    /// assert_eq!(average(&[2, 4, 9]), 5);
+    /// ```
+    ///
+    /// For more info see [`converge`]
+    ///
+    /// [`converge`]: crate::unstable::converge
+    #[inline]
+    fn converge<G, H>(self, g: G, h: H) -> Converge<Self, G, H>
+    where
+        Self: FnOnce<Args>,
+        G: FnOnce<Args>,
+        H: FnOnce<(Self::Output, G::Output)>,
+        Args: Clone,
+    {
+        converge(self, g, h)
+    }
+
+    /// Make recursive function from step function `self` which takes "itself"
+    /// as the first argument.
+    ///
+    /// Note that every recursive call goes through `&dyn Fn(A) -> R`, i.e.
+    /// uses dynamic dispatch, which may prevent inlining.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fib =
+    ///     (|rec: &dyn Fn(u64) -> u64, n: u64| if n < 2 { n } else { rec(n - 1) + rec(n - 2) }).fix();
+    ///
+    /// assert_eq!(fib(10), 55);
+    /// ```
+    ///
+    /// For more info see [`fix`]
+    ///
+    /// [`fix`]: crate::unstable::fix
+    #[inline]
+    fn fix<A, R>(self) -> Fix<Self, R>
+    where
+        Self: Fn(&dyn Fn(A) -> R, A) -> R,
+    {
+        fix(self)
+    }
+
+    /// Box `self`, erasing its type.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "alloc")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::{DynFn, FnExt};
+    ///
+    /// struct Handler {
+    ///     on_event: DynFn<'static, (&'static str, u32), String>,
+    /// }
+    ///
+    /// let format = |name: &str, id: u32| format!("{}#{}", name, id);
+    /// let upper = |s: String| s.to_uppercase();
+    /// let handler = Handler {
+    ///     on_event: format.chain(upper).boxed(),
+    /// };
+    ///
+    /// assert_eq!((handler.on_event)("click", 1), "CLICK#1");
+    /// ```
+    ///
+    /// Boxed function may borrow its environment:
+    /// ```
+    /// use fntools::unstable::{DynFn, FnExt};
+    /// use std::cell::Cell;
+    ///
+    /// let total = Cell::new(0);
+    /// let add = |x: i32| total.set(total.get() + x);
+    /// let double_add = |x: i32| add(x * 2);
+    /// let funs: Vec<DynFn<(i32,), ()>> = vec![add.boxed(), double_add.boxed()];
+    ///
+    /// funs.iter().for_each(|f| f(5));
+    /// assert_eq!(total.get(), 15);
+    /// ```
+    ///
+    /// For more info see [`DynFn`]
+    ///
+    /// [`DynFn`]: crate::unstable::DynFn
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn boxed<'a>(self) -> DynFn<'a, Args, Self::Output>
+    where
+        Self: Fn<Args> + 'a,
+    {
+        DynFn::new(self)
+    }
+
+    /// Count calls of `self`.
+    ///
+    /// Returns version of `self` which increments a shared counter on every
+    /// call, together with a handle to that counter.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "alloc")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let (double, calls) = (|x: i32| x * 2).counted();
+    /// let inc = |x: i32| x + 1;
+    /// let fun = double.chain(inc);
+    ///
+    /// assert_eq!(fun(1), 3);
+    /// assert_eq!(fun(5), 11);
+    /// assert_eq!(calls.get(), 2);
+    /// ```
+    ///
+    /// For more info see [`counted`]
+    ///
+    /// [`counted`]: crate::unstable::counted
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn counted(self) -> (Counted<Self>, Rc<Cell<usize>>)
+    where
+        Self: FnOnce<Args>,
+    {
+        counted(self)
+    }
+
+    /// Cache the most recent `N` results of `self`.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "std")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let len = (|s: &str| {
+    ///     calls.set(calls.get() + 1);
+    ///     s.chars().count()
+    /// })
+    /// .cache_n::<8>();
+    ///
+    /// assert_eq!(len("привет"), 6);
+    /// assert_eq!(len("привет"), 6);
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    ///
+    /// For more info see [`cache_n`]
+    ///
+    /// [`cache_n`]: crate::unstable::cache_n
+    #[cfg(feature = "std")]
+    #[inline]
+    fn cache_n<const N: usize>(self) -> CacheN<Self, Args, Self::Output, N>
+    where
+        Self: FnOnce<Args>,
+        Args: Hash + Eq + Clone,
+        Self::Output: Clone,
+    {
+        cache_n(self)
+    }
+
+    /// Cache the last output of `self`, i.e. skip the call when the argument
+    /// is the same as the previous one.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let len = (|s: &str| {
+    ///     calls.set(calls.get() + 1);
+    ///     s.chars().count()
+    /// })
+    /// .cache_last();
+    ///
+    /// assert_eq!(len("héllo"), 5);
+    /// assert_eq!(len("héllo"), 5);
+    /// assert_eq!(len("hi"), 2);
+    /// assert_eq!(calls.get(), 2);
+    /// ```
+    ///
+    /// For more info see [`cache_last`]
+    ///
+    /// [`cache_last`]: crate::unstable::cache_last
+    #[inline]
+    fn cache_last(self) -> CacheLast<Self, Args, Self::Output>
+    where
+        Self: FnOnce<Args>,
+        Args: PartialEq + Clone,
+        Self::Output: Clone,
+    {
+        cache_last(self)
+    }
+
+    /// Memoize `self`, i.e. cache its output for every distinct argument.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "std")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let fib = (|n: u32| {
+    ///     calls.set(calls.get() + 1);
+    ///     (0..n).fold((0u64, 1u64), |(a, b), _| (b, a + b)).0
+    /// })
+    /// .memoize();
+    ///
+    /// for _ in 0..3 {
+    ///     assert_eq!(fib(10), 55);
+    ///     assert_eq!(fib(20), 6765);
+    /// }
+    /// assert_eq!(calls.get(), 2);
+    /// ```
+    ///
+    /// For more info see [`memoize`]
+    ///
+    /// [`memoize`]: crate::unstable::memoize
+    #[cfg(feature = "std")]
+    #[inline]
+    fn memoize(self) -> Memoize<Self, Args, Self::Output>
+    where
+        Self: FnOnce<Args>,
+        Args: Hash + Eq + Clone,
+        Self::Output: Clone,
+    {
+        memoize(self)
+    }
+
+    /// Measure execution time of `self`, returning it with the output.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "std")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::{thread, time::Duration};
+    ///
+    /// let slow = |ms: u64| {
+    ///     thread::sleep(Duration::from_millis(ms));
+    ///     ms
+    /// };
+    /// let (res, time) = slow.timed()(10);
+    ///
+    /// assert_eq!(res, 10);
+    /// assert!(time >= Duration::from_millis(10));
+    /// ```
+    ///
+    /// For more info see [`timed`]
+    ///
+    /// [`timed`]: crate::unstable::timed
+    #[cfg(feature = "std")]
+    #[inline]
+    fn timed(self) -> Timed<Self>
+    where
+        Self: FnOnce<Args>,
+    {
+        timed(self)
+    }
+
+    /// Use `self` as a [`Service`].
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "service")]`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use fntools::{service::Service, unstable::FnExt};
+    ///
+    /// let inc = |x: i32| x + 1;
+    /// let double = |x: i32| x * 2;
+    /// let mut service = inc.chain(double).into_service();
+    ///
+    /// assert_eq!(service.call(3), 8);
+    /// ```
+    ///
+    /// [`Service`]: crate::service::Service
+    #[cfg(feature = "service")]
+    #[inline]
+    fn into_service(self) -> FnService<Self> { FnService::new(self) }
 }
 
 impl<A, F> FnExt<A> for F
@@ -0,0 +1,393 @@
+/// Call two functions with the same arguments.
+///
+/// Takes functions `f` and `g` and returns function that calls both of them
+/// with (cloned) arguments and returns both outputs as a tuple (in other words
+/// something _like_ `|a: A| (f(a.clone()), g(a))`).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::fanout;
+///
+/// let stats = fanout(str::len, str::is_empty);
+///
+/// assert_eq!(stats("hello"), (5, false));
+/// assert_eq!(stats(""), (0, true));
+/// ```
+///
+/// See also:
+/// - [`converge`]
+/// - parallel version: `par_fanout` (under `#[cfg(feature = "parallel")]`)
+/// - extension on all functions: [`FnExt::fanout`]
+///
+/// [`FnExt::fanout`]: crate::unstable::FnExt::fanout
+#[inline]
+pub fn fanout<A, F, G>(f: F, g: G) -> Fanout<F, G>
+where
+    F: FnOnce<A>,
+    G: FnOnce<A>,
+    A: Clone,
+{
+    Fanout::new(f, g)
+}
+
+/// Represents functions `F` and `G` called with the same arguments.
+///
+/// For documentation see [`fanout`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fanout<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> Fanout<F, G> {
+    /// Creates function that calls both `f` and `g` with the same arguments.
+    ///
+    /// It's preferred to use [`fanout`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A>,
+        G: FnOnce<A>,
+        A: Clone,
+    {
+        Fanout { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let Fanout { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let Fanout { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G> FnOnce<A> for Fanout<F, G>
+where
+    F: FnOnce<A>,
+    G: FnOnce<A>,
+    A: Clone,
+{
+    type Output = (F::Output, G::Output);
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let Fanout { f, g } = self;
+        (f.call_once(args.clone()), g.call_once(args))
+    }
+}
+
+impl<A, F, G> FnMut<A> for Fanout<F, G>
+where
+    F: FnMut<A>,
+    G: FnMut<A>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Fanout { f, g } = self;
+        (f.call_mut(args.clone()), g.call_mut(args))
+    }
+}
+
+impl<A, F, G> Fn<A> for Fanout<F, G>
+where
+    F: Fn<A>,
+    G: Fn<A>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let Fanout { f, g } = self;
+        (f.call(args.clone()), g.call(args))
+    }
+}
+
+/// Call two functions with the same arguments in parallel.
+///
+/// Same as [`fanout`], but `f` and `g` are called on (potentially) different
+/// threads via [`rayon::join`], so this is beneficial when both functions
+/// are expensive.
+///
+/// Since arguments are sent to another thread they must be `Clone + Send`,
+/// and the outputs must be `Send`. The functions themselves must be `Send`
+/// to be called by value or mutable reference and `Sync` to be called by
+/// shared reference.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::par_fanout;
+///
+/// let sum = |v: &[u64]| v.iter().sum::<u64>();
+/// let max = |v: &[u64]| v.iter().copied().max();
+/// let stats = par_fanout(sum, max);
+///
+/// assert_eq!(stats(&[1, 5, 3]), (9, Some(5)));
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::par_fanout`]
+///
+/// **NOTE**: this function is under `#[cfg(feature = "parallel")]`
+///
+/// [`FnExt::par_fanout`]: crate::unstable::FnExt::par_fanout
+#[cfg(feature = "parallel")]
+#[inline]
+pub fn par_fanout<A, F, G>(f: F, g: G) -> ParFanout<F, G>
+where
+    F: FnOnce<A> + Send,
+    G: FnOnce<A> + Send,
+    F::Output: Send,
+    G::Output: Send,
+    A: Clone + Send,
+{
+    ParFanout::new(f, g)
+}
+
+/// Represents functions `F` and `G` called with the same arguments in
+/// parallel.
+///
+/// For documentation see [`par_fanout`].
+#[cfg(feature = "parallel")]
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParFanout<F, G> {
+    f: F,
+    g: G,
+}
+
+#[cfg(feature = "parallel")]
+impl<F, G> ParFanout<F, G> {
+    /// Creates function that calls both `f` and `g` with the same arguments
+    /// in parallel.
+    ///
+    /// It's preferred to use [`par_fanout`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A> + Send,
+        G: FnOnce<A> + Send,
+        F::Output: Send,
+        G::Output: Send,
+        A: Clone + Send,
+    {
+        ParFanout { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let ParFanout { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let ParFanout { f, g } = self;
+        (f, g)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<A, F, G> FnOnce<A> for ParFanout<F, G>
+where
+    F: FnOnce<A> + Send,
+    G: FnOnce<A> + Send,
+    F::Output: Send,
+    G::Output: Send,
+    A: Clone + Send,
+{
+    type Output = (F::Output, G::Output);
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let ParFanout { f, g } = self;
+        let args2 = args.clone();
+        rayon::join(move || f.call_once(args2), move || g.call_once(args))
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<A, F, G> FnMut<A> for ParFanout<F, G>
+where
+    F: FnMut<A> + Send,
+    G: FnMut<A> + Send,
+    F::Output: Send,
+    G::Output: Send,
+    A: Clone + Send,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let ParFanout { f, g } = self;
+        let args2 = args.clone();
+        rayon::join(move || f.call_mut(args2), move || g.call_mut(args))
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<A, F, G> Fn<A> for ParFanout<F, G>
+where
+    F: Fn<A> + Send + Sync,
+    G: Fn<A> + Send + Sync,
+    F::Output: Send,
+    G::Output: Send,
+    A: Clone + Send,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let ParFanout { f, g } = self;
+        let args2 = args.clone();
+        rayon::join(move || f.call(args2), move || g.call(args))
+    }
+}
+
+/// Call two functions with the same arguments and combine their outputs.
+///
+/// Takes functions `f`, `g` and `h` and returns function that calls `f` and
+/// `g` with (cloned) arguments and then passes both outputs to `h` (in other
+/// words something _like_ `|a: A| h(f(a.clone()), g(a))`).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::converge;
+///
+/// let sum = |v: &[f64]| v.iter().sum::<f64>();
+/// let len = |v: &[f64]| v.len() as f64;
+/// let div = |s: f64, n: f64| s / n;
+/// let average = converge(sum, len, div);
+///
+/// assert_eq!(average(&[1.0, 2.0, 6.0]), 3.0);
+/// ```
+///
+/// See also:
+/// - [`fanout`]
+/// - extension on all functions: [`FnExt::converge`]
+///
+/// [`FnExt::converge`]: crate::unstable::FnExt::converge
+#[inline]
+pub fn converge<A, F, G, H>(f: F, g: G, h: H) -> Converge<F, G, H>
+where
+    F: FnOnce<A>,
+    G: FnOnce<A>,
+    H: FnOnce<(F::Output, G::Output)>,
+    A: Clone,
+{
+    Converge::new(f, g, h)
+}
+
+/// Represents functions `F` and `G` called with the same arguments and
+/// outputs combined by `H`.
+///
+/// For documentation see [`converge`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Converge<F, G, H> {
+    f: F,
+    g: G,
+    h: H,
+}
+
+impl<F, G, H> Converge<F, G, H> {
+    /// Creates function that calls both `f` and `g` with the same arguments
+    /// and combines their outputs with `h`.
+    ///
+    /// It's preferred to use [`converge`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G, h: H) -> Self
+    where
+        F: FnOnce<A>,
+        G: FnOnce<A>,
+        H: FnOnce<(F::Output, G::Output)>,
+        A: Clone,
+    {
+        Converge { f, g, h }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G, H) {
+        let Converge { f, g, h } = self;
+        (f, g, h)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G, &H) {
+        let Converge { f, g, h } = self;
+        (f, g, h)
+    }
+}
+
+impl<A, F, G, H> FnOnce<A> for Converge<F, G, H>
+where
+    F: FnOnce<A>,
+    G: FnOnce<A>,
+    H: FnOnce<(F::Output, G::Output)>,
+    A: Clone,
+{
+    type Output = H::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let Converge { f, g, h } = self;
+        h.call_once((f.call_once(args.clone()), g.call_once(args)))
+    }
+}
+
+impl<A, F, G, H> FnMut<A> for Converge<F, G, H>
+where
+    F: FnMut<A>,
+    G: FnMut<A>,
+    H: FnMut<(F::Output, G::Output)>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Converge { f, g, h } = self;
+        h.call_mut((f.call_mut(args.clone()), g.call_mut(args)))
+    }
+}
+
+impl<A, F, G, H> Fn<A> for Converge<F, G, H>
+where
+    F: Fn<A>,
+    G: Fn<A>,
+    H: Fn<(F::Output, G::Output)>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let Converge { f, g, h } = self;
+        h.call((f.call(args.clone()), g.call(args)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::{fanout, FnExt};
+
+    #[test]
+    fn multiple_args() {
+        let add = |a: i32, b: i32| a + b;
+        let sub = |a: i32, b: i32| a - b;
+        let fun = fanout(add, sub);
+
+        assert_eq!(fun(5, 3), (8, 2));
+    }
+
+    #[test]
+    fn clones_args() {
+        let len = |s: String| s.len();
+        let fun = len.fanout(|s: String| s + "!");
+
+        assert_eq!(fun(String::from("hi")), (2, String::from("hi!")));
+    }
+}
@@ -0,0 +1,126 @@
+/// Filter output of a function.
+///
+/// Takes function `f` and predicate `p` and returns function that calls `f`
+/// and returns its output wrapped in `Some` if `p` holds for it, or `None`
+/// otherwise (in other words something _like_
+/// `|a: A| Some(f(a)).filter(p)`).
+///
+/// This is [`guard`] fused onto the output of a function, convenient when
+/// building `Option`-producing stages.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::filter_output;
+///
+/// let sub = |a: i32, b: i32| a - b;
+/// let positive = |out: &i32| *out > 0;
+/// let fun = filter_output(sub, positive);
+///
+/// assert_eq!(fun(5, 3), Some(2));
+/// assert_eq!(fun(3, 5), None);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::filter_output`]
+///
+/// [`guard`]: crate::guard
+/// [`FnExt::filter_output`]: crate::unstable::FnExt::filter_output
+#[inline]
+pub fn filter_output<A, F, P>(f: F, p: P) -> FilterOutput<F, P>
+where
+    F: FnOnce<A>,
+    P: FnOnce(&F::Output) -> bool,
+{
+    FilterOutput::new(f, p)
+}
+
+/// Represents function `F` with output filtered by `P`.
+///
+/// For documentation see [`filter_output`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FilterOutput<F, P> {
+    f: F,
+    p: P,
+}
+
+impl<F, P> FilterOutput<F, P> {
+    /// Creates version of the function `f` with output filtered by `p`.
+    ///
+    /// It's preferred to use [`filter_output`] instead.
+    #[inline]
+    pub fn new<A>(f: F, p: P) -> Self
+    where
+        F: FnOnce<A>,
+        P: FnOnce(&F::Output) -> bool,
+    {
+        FilterOutput { f, p }
+    }
+
+    /// Returns inner function and predicate.
+    #[inline]
+    pub fn into_inner(self) -> (F, P) {
+        let FilterOutput { f, p } = self;
+        (f, p)
+    }
+
+    /// Returns references to inner function and predicate.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &P) {
+        let FilterOutput { f, p } = self;
+        (f, p)
+    }
+}
+
+impl<A, F, P> FnOnce<A> for FilterOutput<F, P>
+where
+    F: FnOnce<A>,
+    P: FnOnce(&F::Output) -> bool,
+{
+    type Output = Option<F::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let FilterOutput { f, p } = self;
+        let res = f.call_once(args);
+        if p(&res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+impl<A, F, P> FnMut<A> for FilterOutput<F, P>
+where
+    F: FnMut<A>,
+    P: FnMut(&F::Output) -> bool,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let FilterOutput { f, p } = self;
+        let res = f.call_mut(args);
+        if p(&res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+impl<A, F, P> Fn<A> for FilterOutput<F, P>
+where
+    F: Fn<A>,
+    P: Fn(&F::Output) -> bool,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let FilterOutput { f, p } = self;
+        let res = f.call(args);
+        if p(&res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
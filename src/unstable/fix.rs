@@ -0,0 +1,143 @@
+use core::{
+    fmt::{Debug, Error, Formatter},
+    marker::PhantomData,
+};
+
+/// Fixed-point combinator, allows writing recursive closures.
+///
+/// Takes step function `f` which accepts "itself" as the first argument and
+/// returns function that calls `f` passing itself as that argument.
+///
+/// Recursive calls go through `&dyn Fn(A) -> R`, i.e. every recursion step
+/// uses dynamic dispatch (this is unavoidable since the type of the closure
+/// can't mention itself).
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::fix;
+///
+/// let factorial = fix(|rec: &dyn Fn(u64) -> u64, n: u64| if n == 0 { 1 } else { n * rec(n - 1) });
+///
+/// assert_eq!(factorial(5), 120);
+/// ```
+///
+/// The step function has to be `Fn` (recursion calls it while it's already
+/// running, so it can't be borrowed uniquely), use [`Cell`] or [`RefCell`]
+/// to accumulate state:
+/// ```
+/// use fntools::unstable::fix;
+/// use std::cell::RefCell;
+///
+/// let visited = RefCell::new(Vec::new());
+/// let countdown = fix(|rec: &dyn Fn(u32), n: u32| {
+///     visited.borrow_mut().push(n);
+///     if n > 0 {
+///         rec(n - 1)
+///     }
+/// });
+///
+/// countdown(3);
+/// assert_eq!(*visited.borrow(), [3, 2, 1, 0]);
+/// ```
+///
+/// [`Cell`]: std::cell::Cell
+/// [`RefCell`]: std::cell::RefCell
+#[inline]
+pub fn fix<A, R, F>(f: F) -> Fix<F, R>
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    Fix::new(f)
+}
+
+/// Represents recursive function built from step function `F`.
+///
+/// For documentation see [`fix`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct Fix<F, R> {
+    f: F,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<F, R> Fix<F, R> {
+    /// Creates recursive function from step function `f`.
+    ///
+    /// It's preferred to use [`fix`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: Fn(&dyn Fn(A) -> R, A) -> R,
+    {
+        Fix {
+            f,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let Fix { f, marker: _ } = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let Fix { f, marker: _ } = self;
+        f
+    }
+}
+
+impl<A, R, F> FnOnce<(A,)> for Fix<F, R>
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    type Output = R;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: (A,)) -> Self::Output { self.call(args) }
+}
+
+impl<A, R, F> FnMut<(A,)> for Fix<F, R>
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: (A,)) -> Self::Output { self.call(args) }
+}
+
+impl<A, R, F> Fn<(A,)> for Fix<F, R>
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (arg,): (A,)) -> Self::Output {
+        let Fix { f, .. } = self;
+        f(self, arg)
+    }
+}
+
+impl<F, R> Debug for Fix<F, R>
+where
+    F: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_tuple("Fix").field(&self.f).finish()
+    }
+}
+
+impl<F, R> Clone for Fix<F, R>
+where
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Fix {
+            f: self.f.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F, R> Copy for Fix<F, R> where F: Copy {}
@@ -0,0 +1,98 @@
+/// Flatten nested result of a fallible function.
+///
+/// Takes function `f` returning `Result<Result<T, E>, E>` and returns function
+/// that calls `f` and flattens its output into `Result<T, E>` (in other words
+/// something _like_ `|a: A| f(a).and_then(|res| res)`).
+///
+/// Such nested results arise e.g. when [`chain`]ing a fallible function with
+/// a function that maps its `Ok` value with another fallible function.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::flatten_result;
+///
+/// let parse = |s: &str| s.parse::<u32>().map_err(|_| "not a number");
+/// let check = |n: u32| if n % 2 == 0 { Ok(n) } else { Err("odd") };
+/// let fun = flatten_result(move |s: &str| parse(s).map(check));
+///
+/// assert_eq!(fun("4"), Ok(4));
+/// // outer `Err`
+/// assert_eq!(fun("x"), Err("not a number"));
+/// // inner `Err`
+/// assert_eq!(fun("3"), Err("odd"));
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::flatten_result`]
+///
+/// [`chain`]: crate::unstable::chain
+/// [`FnExt::flatten_result`]: crate::unstable::FnExt::flatten_result
+#[inline]
+pub fn flatten_result<A, T, E, F>(f: F) -> FlattenResult<F>
+where
+    F: FnOnce<A, Output = Result<Result<T, E>, E>>,
+{
+    FlattenResult::new(f)
+}
+
+/// Represents fallible function `F` with flattened output.
+///
+/// For documentation see [`flatten_result`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlattenResult<F> {
+    f: F,
+}
+
+impl<F> FlattenResult<F> {
+    /// Creates version of the function `f` with flattened output.
+    ///
+    /// It's preferred to use [`flatten_result`] instead.
+    #[inline]
+    pub fn new<A, T, E>(f: F) -> Self
+    where
+        F: FnOnce<A, Output = Result<Result<T, E>, E>>,
+    {
+        FlattenResult { f }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<A, T, E, F> FnOnce<A> for FlattenResult<F>
+where
+    F: FnOnce<A, Output = Result<Result<T, E>, E>>,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        self.f.call_once(args).and_then(|res| res)
+    }
+}
+
+impl<A, T, E, F> FnMut<A> for FlattenResult<F>
+where
+    F: FnMut<A, Output = Result<Result<T, E>, E>>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        self.f.call_mut(args).and_then(|res| res)
+    }
+}
+
+impl<A, T, E, F> Fn<A> for FlattenResult<F>
+where
+    F: Fn<A, Output = Result<Result<T, E>, E>>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        self.f.call(args).and_then(|res| res)
+    }
+}
@@ -23,7 +23,7 @@ where
 ///
 /// For documentation see [`flip`].
 #[must_use = "function combinators are lazy and do nothing unless called"]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Flip<F>(F);
 
 impl<F> Flip<F> {
@@ -94,3 +94,69 @@ where
         res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::flip;
+
+    #[test]
+    fn clone() {
+        let sep = String::from(", ");
+        let join = move |a: &str, b: i32| format!("{}{}{}", a, sep, b);
+        let fun = flip(join);
+        let clone = fun.clone();
+
+        assert_eq!(fun(1, "a"), "a, 1");
+        assert_eq!(clone(1, "a"), fun(1, "a"));
+    }
+
+    #[test]
+    fn copy() {
+        let fun = flip(|a: i32, b: i32| a - b);
+        let copy = fun;
+
+        assert_eq!(copy(1, 10), 9);
+        assert_eq!(copy(1, 10), fun(1, 10));
+    }
+
+    #[test]
+    fn debug() {
+        let sub: fn(i32, i32) -> i32 = |a, b| a - b;
+
+        assert_eq!(format!("{:?}", flip(sub)), format!("Flip({:?})", sub));
+    }
+
+    #[test]
+    fn fn_mut() {
+        let mut log = Vec::new();
+        let record = |a: i32, b: char| {
+            log.push((a, b));
+            log.len()
+        };
+        let mut fun = flip(record);
+
+        // `fun(...)` syntax picks `Fn` impl, so `call_mut` is used explicitly
+        assert_eq!(fun.call_mut(('a', 1)), 1);
+        assert_eq!(fun.call_mut(('b', 2)), 2);
+        assert_eq!(fun.call_mut(('c', 3)), 3);
+        drop(fun);
+        assert_eq!(log, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    }
+
+    #[test]
+    fn fn_shared() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let count = |a: u32, b: u32| {
+            calls.set(calls.get() + 1);
+            a * 10 + b
+        };
+        let fun = flip(count);
+        let by_ref = &fun;
+
+        assert_eq!(by_ref(1, 2), 21);
+        assert_eq!(fun(3, 4), 43);
+        assert_eq!(calls.get(), 2);
+    }
+}
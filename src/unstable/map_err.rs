@@ -0,0 +1,110 @@
+/// Map error of a fallible function.
+///
+/// Takes functions `f` and `m` and returns function that calls `f` and maps
+/// its error with `m` (in other words something _like_ `|a: A|
+/// f(a).map_err(m)`).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::map_err;
+/// use std::num::ParseIntError;
+///
+/// let parse = map_err(str::parse::<i32>, |e: ParseIntError| e.to_string());
+///
+/// assert_eq!(parse("17"), Ok(17));
+/// assert_eq!(
+///     parse(""),
+///     Err(String::from("cannot parse integer from empty string"))
+/// );
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::map_err`]
+/// - extension on all functions: [`FnExt::map_err`]
+///
+/// [`FnExt::map_err`]: crate::unstable::FnExt::map_err
+/// [`fntools::map_err`]: crate::map_err
+#[inline]
+pub fn map_err<A, T, E, F, M>(f: F, m: M) -> MapErr<F, M>
+where
+    F: FnOnce<A, Output = Result<T, E>>,
+    M: FnOnce<(E,)>,
+{
+    MapErr::new(f, m)
+}
+
+/// Represents fallible function `F` with error mapped by `M`.
+///
+/// For documentation see [`map_err`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapErr<F, M> {
+    f: F,
+    m: M,
+}
+
+impl<F, M> MapErr<F, M> {
+    /// Creates version of the function `f` with error mapped by `m`.
+    ///
+    /// It's preferred to use [`map_err`] instead.
+    #[inline]
+    pub fn new<A, T, E>(f: F, m: M) -> Self
+    where
+        F: FnOnce<A, Output = Result<T, E>>,
+        M: FnOnce<(E,)>,
+    {
+        MapErr { f, m }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, M) {
+        let MapErr { f, m } = self;
+        (f, m)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &M) {
+        let MapErr { f, m } = self;
+        (f, m)
+    }
+}
+
+impl<A, T, E, F, M> FnOnce<A> for MapErr<F, M>
+where
+    F: FnOnce<A, Output = Result<T, E>>,
+    M: FnOnce<(E,)>,
+{
+    type Output = Result<T, M::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let MapErr { f, m } = self;
+        f.call_once(args).map_err(m)
+    }
+}
+
+impl<A, T, E, F, M> FnMut<A> for MapErr<F, M>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    M: FnMut<(E,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let MapErr { f, m } = self;
+        f.call_mut(args).map_err(m)
+    }
+}
+
+impl<A, T, E, F, M> Fn<A> for MapErr<F, M>
+where
+    F: Fn<A, Output = Result<T, E>>,
+    M: Fn<(E,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let MapErr { f, m } = self;
+        f.call(args).map_err(m)
+    }
+}
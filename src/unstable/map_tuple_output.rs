@@ -0,0 +1,115 @@
+use crate::tuple::map::TupleMap;
+
+/// Map every element of the tuple output of a function.
+///
+/// Takes function `f` returning homogeneous tuple (of arity up to 8) and
+/// function `m` and returns function that calls `f` and maps every element of
+/// its output with `m` (in other words something _like_
+/// `|a: A| { let (x, y, z) = f(a); (m(x), m(y), m(z)) }`).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::map_tuple_output;
+///
+/// let split = |s: &'static str| (&s[..1], &s[1..]);
+/// let fun = map_tuple_output(split, str::len);
+///
+/// assert_eq!(fun("hello"), (1, 4));
+/// ```
+///
+/// See also:
+/// - [`TupleMap`]
+/// - extension on all functions: [`FnExt::map_tuple_output`]
+///
+/// [`TupleMap`]: crate::tuple::map::TupleMap
+/// [`FnExt::map_tuple_output`]: crate::unstable::FnExt::map_tuple_output
+#[inline]
+pub fn map_tuple_output<A, F, M>(f: F, m: M) -> MapTupleOutput<F, M>
+where
+    F: FnOnce<A>,
+    F::Output: TupleMap,
+    M: FnMut<(<F::Output as TupleMap>::Item,)>,
+{
+    MapTupleOutput::new(f, m)
+}
+
+/// Represents function `F` with every element of the output mapped by `M`.
+///
+/// For documentation see [`map_tuple_output`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapTupleOutput<F, M> {
+    f: F,
+    m: M,
+}
+
+impl<F, M> MapTupleOutput<F, M> {
+    /// Creates version of the function `f` with every element of the output
+    /// mapped by `m`.
+    ///
+    /// It's preferred to use [`map_tuple_output`] instead.
+    #[inline]
+    pub fn new<A>(f: F, m: M) -> Self
+    where
+        F: FnOnce<A>,
+        F::Output: TupleMap,
+        M: FnMut<(<F::Output as TupleMap>::Item,)>,
+    {
+        MapTupleOutput { f, m }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, M) {
+        let MapTupleOutput { f, m } = self;
+        (f, m)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &M) {
+        let MapTupleOutput { f, m } = self;
+        (f, m)
+    }
+}
+
+impl<A, F, M> FnOnce<A> for MapTupleOutput<F, M>
+where
+    F: FnOnce<A>,
+    F::Output: TupleMap,
+    M: FnMut<(<F::Output as TupleMap>::Item,)>,
+{
+    type Output = <F::Output as TupleMap>::Res<M::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let MapTupleOutput { f, mut m } = self;
+        f.call_once(args).map(|x| m.call_mut((x,)))
+    }
+}
+
+impl<A, F, M> FnMut<A> for MapTupleOutput<F, M>
+where
+    F: FnMut<A>,
+    F::Output: TupleMap,
+    M: FnMut<(<F::Output as TupleMap>::Item,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let MapTupleOutput { f, m } = self;
+        f.call_mut(args).map(|x| m.call_mut((x,)))
+    }
+}
+
+impl<A, F, M> Fn<A> for MapTupleOutput<F, M>
+where
+    F: Fn<A>,
+    F::Output: TupleMap,
+    M: Fn<(<F::Output as TupleMap>::Item,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let MapTupleOutput { f, m } = self;
+        f.call(args).map(|x| m.call((x,)))
+    }
+}
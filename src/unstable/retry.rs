@@ -0,0 +1,340 @@
+/// Retry fallible function.
+///
+/// Takes function `f` and returns function that calls `f` up to `attempts`
+/// times (with cloned arguments) until it returns `Ok`. If all attempts
+/// failed, the last `Err` is returned.
+///
+/// `f` is always called at least once, so `attempts == 0` is the same as
+/// `attempts == 1`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::retry;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let flaky = |x: i32| {
+///     calls.set(calls.get() + 1);
+///     if calls.get() < 3 {
+///         Err("not yet")
+///     } else {
+///         Ok(x)
+///     }
+/// };
+/// let fun = retry(flaky, 3);
+///
+/// assert_eq!(fun(17), Ok(17));
+/// assert_eq!(calls.get(), 3);
+/// ```
+///
+/// See also:
+/// - version with a hook between attempts: [`retry_with`]
+/// - extension on all functions: [`FnExt::retry`]
+///
+/// [`FnExt::retry`]: crate::unstable::FnExt::retry
+#[inline]
+pub fn retry<A, T, E, F>(f: F, attempts: usize) -> Retry<F>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    Retry::new(f, attempts)
+}
+
+/// Represents fallible function `F` which is retried on errors.
+///
+/// For documentation see [`retry`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Retry<F> {
+    f: F,
+    attempts: usize,
+}
+
+impl<F> Retry<F> {
+    /// Creates version of the function `f` which is retried up to `attempts`
+    /// times.
+    ///
+    /// It's preferred to use [`retry`] instead.
+    #[inline]
+    pub fn new<A, T, E>(f: F, attempts: usize) -> Self
+    where
+        F: FnMut<A, Output = Result<T, E>>,
+        A: Clone,
+    {
+        Retry { f, attempts }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+
+    /// Returns number of attempts.
+    #[inline]
+    pub fn attempts(&self) -> usize { self.attempts }
+}
+
+impl<A, T, E, F> FnOnce<A> for Retry<F>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    extern "rust-call" fn call_once(mut self, args: A) -> Self::Output { self.call_mut(args) }
+}
+
+impl<A, T, E, F> FnMut<A> for Retry<F>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Retry { f, attempts } = self;
+        for _ in 1..*attempts {
+            if let Ok(res) = f.call_mut(args.clone()) {
+                return Ok(res);
+            }
+        }
+        f.call_mut(args)
+    }
+}
+
+impl<A, T, E, F> Fn<A> for Retry<F>
+where
+    F: Fn<A, Output = Result<T, E>>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let Retry { f, attempts } = self;
+        for _ in 1..*attempts {
+            if let Ok(res) = f.call(args.clone()) {
+                return Ok(res);
+            }
+        }
+        f.call(args)
+    }
+}
+
+/// Retry fallible function, calling a hook between attempts.
+///
+/// Same as [`retry`], but after every failed attempt but the last one
+/// `on_retry` is called with the (1-based) number of the failed attempt and a
+/// reference to the error. This keeps timing policy (e.g. sleeping with a
+/// backoff) and logging out of the core retry logic.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::retry_with;
+/// use std::cell::{Cell, RefCell};
+///
+/// let calls = Cell::new(0);
+/// let flaky = |x: i32| {
+///     calls.set(calls.get() + 1);
+///     if calls.get() < 3 {
+///         Err(calls.get())
+///     } else {
+///         Ok(x)
+///     }
+/// };
+/// let retries = RefCell::new(Vec::new());
+/// let on_retry = |attempt: usize, err: &usize| retries.borrow_mut().push((attempt, *err));
+/// let fun = retry_with(flaky, 5, on_retry);
+///
+/// assert_eq!(fun(17), Ok(17));
+/// assert_eq!(*retries.borrow(), [(1, 1), (2, 2)]);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::retry_with`]
+///
+/// [`FnExt::retry_with`]: crate::unstable::FnExt::retry_with
+#[inline]
+pub fn retry_with<A, T, E, F, H>(f: F, attempts: usize, on_retry: H) -> RetryWith<F, H>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    H: FnMut(usize, &E),
+    A: Clone,
+{
+    RetryWith::new(f, attempts, on_retry)
+}
+
+/// Represents fallible function `F` which is retried on errors, calling `H`
+/// between attempts.
+///
+/// For documentation see [`retry_with`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetryWith<F, H> {
+    f: F,
+    attempts: usize,
+    on_retry: H,
+}
+
+impl<F, H> RetryWith<F, H> {
+    /// Creates version of the function `f` which is retried up to `attempts`
+    /// times, calling `on_retry` between attempts.
+    ///
+    /// It's preferred to use [`retry_with`] instead.
+    #[inline]
+    pub fn new<A, T, E>(f: F, attempts: usize, on_retry: H) -> Self
+    where
+        F: FnMut<A, Output = Result<T, E>>,
+        H: FnMut(usize, &E),
+        A: Clone,
+    {
+        RetryWith {
+            f,
+            attempts,
+            on_retry,
+        }
+    }
+
+    /// Returns inner function and hook.
+    #[inline]
+    pub fn into_inner(self) -> (F, H) {
+        let RetryWith { f, on_retry, .. } = self;
+        (f, on_retry)
+    }
+
+    /// Returns references to inner function and hook.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &H) {
+        let RetryWith { f, on_retry, .. } = self;
+        (f, on_retry)
+    }
+
+    /// Returns number of attempts.
+    #[inline]
+    pub fn attempts(&self) -> usize { self.attempts }
+}
+
+impl<A, T, E, F, H> FnOnce<A> for RetryWith<F, H>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    H: FnMut(usize, &E),
+    A: Clone,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    extern "rust-call" fn call_once(mut self, args: A) -> Self::Output { self.call_mut(args) }
+}
+
+impl<A, T, E, F, H> FnMut<A> for RetryWith<F, H>
+where
+    F: FnMut<A, Output = Result<T, E>>,
+    H: FnMut(usize, &E),
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let RetryWith {
+            f,
+            attempts,
+            on_retry,
+        } = self;
+        for attempt in 1..*attempts {
+            match f.call_mut(args.clone()) {
+                Ok(res) => return Ok(res),
+                Err(err) => on_retry(attempt, &err),
+            }
+        }
+        f.call_mut(args)
+    }
+}
+
+impl<A, T, E, F, H> Fn<A> for RetryWith<F, H>
+where
+    F: Fn<A, Output = Result<T, E>>,
+    H: Fn(usize, &E),
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let RetryWith {
+            f,
+            attempts,
+            on_retry,
+        } = self;
+        for attempt in 1..*attempts {
+            match f.call(args.clone()) {
+                Ok(res) => return Ok(res),
+                Err(err) => on_retry(attempt, &err),
+            }
+        }
+        f.call(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::unstable::{retry, retry_with, FnExt};
+
+    #[test]
+    fn last_err() {
+        let calls = Cell::new(0);
+        let fail = |x: i32| {
+            calls.set(calls.get() + 1);
+            Err::<(), _>(x + calls.get())
+        };
+        let fun = retry(fail, 4);
+
+        assert_eq!(fun(10), Err(14));
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn zero_attempts() {
+        let calls = Cell::new(0);
+        let fail = |()| {
+            calls.set(calls.get() + 1);
+            Err::<(), _>(())
+        };
+        let fun = fail.retry(0);
+
+        assert_eq!(fun(()), Err(()));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn fn_mut() {
+        let mut calls = 0;
+        let mut fun = retry(
+            |x: i32| {
+                calls += 1;
+                if calls % 2 == 0 {
+                    Ok(x)
+                } else {
+                    Err(())
+                }
+            },
+            2,
+        );
+
+        assert_eq!(fun.call_mut((1,)), Ok(1));
+        assert_eq!(fun.call_mut((2,)), Ok(2));
+        drop(fun);
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn no_hook_after_last_attempt() {
+        let hooks = Cell::new(0);
+        let fail = |x: i32| Err::<(), _>(x);
+        let hook = |_: usize, _: &i32| hooks.set(hooks.get() + 1);
+        let fun = retry_with(fail, 3, hook);
+
+        assert_eq!(fun(1), Err(1));
+        assert_eq!(hooks.get(), 2);
+    }
+}
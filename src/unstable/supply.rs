@@ -1,9 +1,12 @@
-use std::{
+use core::{
     fmt::{Debug, Error, Formatter},
+    hash::{Hash, Hasher},
     marker::PhantomData,
 };
 
-use crate::tuple::{append::TupleAppend, take::TupleTake};
+use crate::tuple::{
+    append::TupleAppend, push::TuplePush, take::TupleTake, take_last::TupleTakeLast,
+};
 
 /// Supply `argument` to the function `f`.
 ///
@@ -27,6 +30,18 @@ use crate::tuple::{append::TupleAppend, take::TupleTake};
 ///
 /// assert_eq!(fun(), "a: 8, b: 16, c: \"AAA\"");
 /// ```
+///
+/// When the supplied argument is `Clone`, the function can be called many
+/// times (the argument is cloned on every call):
+/// ```
+/// use fntools::unstable::supply;
+///
+/// let greet = |greeting: String, name: &str| format!("{}, {}!", greeting, name);
+/// let hello = supply(greet, String::from("Hello"));
+///
+/// assert_eq!(hello("Alice"), "Hello, Alice!");
+/// assert_eq!(hello("Bob"), "Hello, Bob!");
+/// ```
 #[inline]
 pub fn supply<F, A>(f: F, argument: A::Take) -> Supply<A::Take, F, A>
 where
@@ -163,3 +178,294 @@ where
     F: Copy,
 {
 }
+
+impl<T, F, A> PartialEq for Supply<T, F, A>
+where
+    T: PartialEq,
+    F: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.argument == other.argument && self.f == other.f }
+}
+
+impl<T, F, A> Eq for Supply<T, F, A>
+where
+    T: Eq,
+    F: Eq,
+{
+}
+
+impl<T, F, A> Hash for Supply<T, F, A>
+where
+    T: Hash,
+    F: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.argument.hash(state);
+        self.f.hash(state);
+    }
+}
+
+/// Supply the last `argument` to the function `f`.
+///
+/// This is the same as [`supply`], but binds the **last** argument of `f`
+/// instead of the first one, leaving the leading arguments open.
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::supply_right;
+///
+/// let div = |num: i32, den: i32| num / den;
+/// let half = supply_right(div, 2);
+///
+/// assert_eq!(half(10), 5);
+/// assert_eq!(half(7), 3);
+/// ```
+/// ```
+/// use fntools::unstable::supply_right;
+///
+/// let fun = |a: i32, b: usize, c: String| format!("a: {}, b: {}, c: {:?}", a, b, c);
+/// let fun = supply_right(fun, String::from("AAA"));
+/// let fun = supply_right(fun, 16);
+///
+/// assert_eq!(fun(8), "a: 8, b: 16, c: \"AAA\"");
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::supply_right`]
+///
+/// [`FnExt::supply_right`]: crate::unstable::FnExt::supply_right
+#[inline]
+pub fn supply_right<F, A>(f: F, argument: A::Take) -> SupplyRight<A::Take, F, A>
+where
+    F: FnOnce<A>,
+    A: TupleTakeLast,
+{
+    SupplyRight::new(f, argument)
+}
+
+/// Represents function `F` with supplied last argument `T`.
+///
+/// See [`supply_right`] for documentation.
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct SupplyRight<T, F, A> {
+    argument: T,
+    f: F,
+    marker: PhantomData<fn(A)>,
+}
+
+impl<T, F, A> SupplyRight<T, F, A> {
+    /// Creates version of the functions `f` with supplied last `argument`.
+    ///
+    /// It's preferred to use [`supply_right`] instead.
+    #[inline]
+    pub fn new(f: F, argument: T) -> Self
+    where
+        F: FnOnce<A>,
+        A: TupleTakeLast<Take = T>,
+    {
+        SupplyRight {
+            argument,
+            f,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns inner function and supplied argument.
+    #[inline]
+    pub fn into_inner(self) -> (F, T) {
+        let SupplyRight {
+            f,
+            argument,
+            marker: _,
+        } = self;
+        (f, argument)
+    }
+
+    /// Returns references to function and supplied argument.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &T) {
+        let SupplyRight {
+            f,
+            argument,
+            marker: _,
+        } = self;
+        (f, argument)
+    }
+}
+
+impl<T, E, F> FnOnce<E> for SupplyRight<T, F, E::Res>
+where
+    F: FnOnce<E::Res>,
+    E: TuplePush<T>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: E) -> Self::Output {
+        let SupplyRight { argument, f, .. } = self;
+        f.call_once(args.push(argument))
+    }
+}
+
+impl<T, E, F> FnMut<E> for SupplyRight<T, F, E::Res>
+where
+    F: FnMut<E::Res>,
+    E: TuplePush<T>,
+    T: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: E) -> Self::Output {
+        let SupplyRight { argument, f, .. } = self;
+        f.call_mut(args.push(argument.clone()))
+    }
+}
+
+impl<T, E, F> Fn<E> for SupplyRight<T, F, E::Res>
+where
+    F: Fn<E::Res>,
+    E: TuplePush<T>,
+    T: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: E) -> Self::Output {
+        let SupplyRight { argument, f, .. } = self;
+        f.call(args.push(argument.clone()))
+    }
+}
+
+impl<T, F, A> Debug for SupplyRight<T, F, A>
+where
+    T: Debug,
+    F: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("SupplyRight")
+            .field("argument", &self.argument)
+            .field("f", &self.f)
+            .finish()
+    }
+}
+
+impl<T, F, A> Clone for SupplyRight<T, F, A>
+where
+    T: Clone,
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        SupplyRight {
+            argument: self.argument.clone(),
+            f: self.f.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, F, A> Copy for SupplyRight<T, F, A>
+where
+    T: Copy,
+    F: Copy,
+{
+}
+
+impl<T, F, A> PartialEq for SupplyRight<T, F, A>
+where
+    T: PartialEq,
+    F: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.argument == other.argument && self.f == other.f }
+}
+
+impl<T, F, A> Eq for SupplyRight<T, F, A>
+where
+    T: Eq,
+    F: Eq,
+{
+}
+
+impl<T, F, A> Hash for SupplyRight<T, F, A>
+where
+    T: Hash,
+    F: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.argument.hash(state);
+        self.f.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::{supply, supply_right};
+
+    #[test]
+    fn clone() {
+        let greet = |greeting: String, name: &str| format!("{}, {}!", greeting, name);
+        let hello = supply(greet, String::from("Hello"));
+        let clone = hello.clone();
+
+        assert_eq!(hello("Alice"), "Hello, Alice!");
+        assert_eq!(clone("Bob"), "Hello, Bob!");
+    }
+
+    #[test]
+    fn copy() {
+        let add = |a: i32, b: i32| a + b;
+        let add_5 = supply(add, 5);
+        let copy = add_5;
+
+        assert_eq!(add_5(1), 6);
+        assert_eq!(copy(2), 7);
+    }
+
+    #[test]
+    fn debug() {
+        let add: fn(i32, i32) -> i32 = |a, b| a + b;
+
+        assert_eq!(
+            format!("{:?}", supply(add, 5)),
+            format!("Supply {{ argument: 5, f: {:?} }}", add)
+        );
+    }
+
+    #[test]
+    fn eq_hash() {
+        use std::collections::HashSet;
+
+        let add: fn(i32, i32) -> i32 = |a, b| a + b;
+        let sub: fn(i32, i32) -> i32 = |a, b| a - b;
+
+        assert_eq!(supply(add, 5), supply(add, 5));
+        assert_ne!(supply(add, 5), supply(add, 6));
+        assert_ne!(supply(add, 5), supply(sub, 5));
+
+        let set: HashSet<_> = vec![supply(add, 1), supply(add, 2), supply(add, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn move_only_argument() {
+        // neither `Clone` nor `Copy`
+        struct Token(u8);
+
+        let consume = |token: Token, prefix: &str| format!("{}{}", prefix, token.0);
+        let fun = supply(consume, Token(7));
+
+        assert_eq!(fun("#"), "#7");
+    }
+
+    #[test]
+    fn supply_right_order() {
+        let fun = |a: &str, b: &str, c: &str| format!("{}{}{}", a, b, c);
+        let fun = supply_right(fun, "c");
+
+        assert_eq!(fun("a", "b"), "abc");
+        assert_eq!(supply_right(fun, "b")("a"), "abc");
+    }
+}
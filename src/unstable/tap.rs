@@ -0,0 +1,235 @@
+/// Inspect output of a function.
+///
+/// Takes functions `f` and `t` and returns function that calls `f`, passes a
+/// reference to its output to `t` and then returns the output unchanged (in
+/// other words something _like_ `|a: A| { let out = f(a); t(&out); out }`).
+///
+/// This is useful for side effects like logging or collecting metrics in the
+/// middle of a pipeline.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::tap;
+/// use std::cell::Cell;
+///
+/// let seen = Cell::new(0);
+/// let add = |a: i32, b: i32| a + b;
+/// let log = |out: &i32| seen.set(*out);
+/// let add = tap(add, log);
+///
+/// assert_eq!(add(2, 3), 5);
+/// assert_eq!(seen.get(), 5);
+/// ```
+///
+/// See also:
+/// - input-side counterpart: [`inspect_args`]
+/// - extension on all functions: [`FnExt::tap`]
+///
+/// [`FnExt::tap`]: crate::unstable::FnExt::tap
+#[inline]
+pub fn tap<A, F, T>(f: F, t: T) -> Tap<F, T>
+where
+    F: FnOnce<A>,
+    T: FnOnce(&F::Output),
+{
+    Tap::new(f, t)
+}
+
+/// Represents function `F` with output inspected by `T`.
+///
+/// For documentation see [`tap`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tap<F, T> {
+    f: F,
+    t: T,
+}
+
+impl<F, T> Tap<F, T> {
+    /// Creates version of the function `f` with output inspected by `t`.
+    ///
+    /// It's preferred to use [`tap`] instead.
+    #[inline]
+    pub fn new<A>(f: F, t: T) -> Self
+    where
+        F: FnOnce<A>,
+        T: FnOnce(&F::Output),
+    {
+        Tap { f, t }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, T) {
+        let Tap { f, t } = self;
+        (f, t)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &T) {
+        let Tap { f, t } = self;
+        (f, t)
+    }
+}
+
+impl<A, F, T> FnOnce<A> for Tap<F, T>
+where
+    F: FnOnce<A>,
+    T: FnOnce(&F::Output),
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let Tap { f, t } = self;
+        let res = f.call_once(args);
+        t(&res);
+        res
+    }
+}
+
+impl<A, F, T> FnMut<A> for Tap<F, T>
+where
+    F: FnMut<A>,
+    T: FnMut(&F::Output),
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Tap { f, t } = self;
+        let res = f.call_mut(args);
+        t(&res);
+        res
+    }
+}
+
+impl<A, F, T> Fn<A> for Tap<F, T>
+where
+    F: Fn<A>,
+    T: Fn(&F::Output),
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let Tap { f, t } = self;
+        let res = f.call(args);
+        t(&res);
+        res
+    }
+}
+
+/// Inspect arguments of a function.
+///
+/// Takes functions `f` and `i` and returns function that passes a reference
+/// to the tuple of arguments to `i`, then calls `f` with these arguments and
+/// returns its output unchanged (in other words something _like_
+/// `|a: A| { i(&a); f(a) }`).
+///
+/// This is the input-side counterpart of [`tap`], useful e.g. for tracing
+/// which arguments flowed through a composed pipeline.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::inspect_args;
+/// use std::cell::RefCell;
+///
+/// let seen = RefCell::new(Vec::new());
+/// let add = |a: i32, b: i32| a + b;
+/// let record = |args: &(i32, i32)| seen.borrow_mut().push(*args);
+/// let add = inspect_args(add, record);
+///
+/// assert_eq!(add(2, 3), 5);
+/// assert_eq!(add(4, 5), 9);
+/// assert_eq!(*seen.borrow(), [(2, 3), (4, 5)]);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::inspect_args`]
+///
+/// [`FnExt::inspect_args`]: crate::unstable::FnExt::inspect_args
+#[inline]
+pub fn inspect_args<A, F, I>(f: F, i: I) -> InspectArgs<F, I>
+where
+    F: FnOnce<A>,
+    I: FnOnce(&A),
+{
+    InspectArgs::new(f, i)
+}
+
+/// Represents function `F` with arguments inspected by `I`.
+///
+/// For documentation see [`inspect_args`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InspectArgs<F, I> {
+    f: F,
+    i: I,
+}
+
+impl<F, I> InspectArgs<F, I> {
+    /// Creates version of the function `f` with arguments inspected by `i`.
+    ///
+    /// It's preferred to use [`inspect_args`] instead.
+    #[inline]
+    pub fn new<A>(f: F, i: I) -> Self
+    where
+        F: FnOnce<A>,
+        I: FnOnce(&A),
+    {
+        InspectArgs { f, i }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, I) {
+        let InspectArgs { f, i } = self;
+        (f, i)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &I) {
+        let InspectArgs { f, i } = self;
+        (f, i)
+    }
+}
+
+impl<A, F, I> FnOnce<A> for InspectArgs<F, I>
+where
+    F: FnOnce<A>,
+    I: FnOnce(&A),
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let InspectArgs { f, i } = self;
+        i(&args);
+        f.call_once(args)
+    }
+}
+
+impl<A, F, I> FnMut<A> for InspectArgs<F, I>
+where
+    F: FnMut<A>,
+    I: FnMut(&A),
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let InspectArgs { f, i } = self;
+        i(&args);
+        f.call_mut(args)
+    }
+}
+
+impl<A, F, I> Fn<A> for InspectArgs<F, I>
+where
+    F: Fn<A>,
+    I: Fn(&A),
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let InspectArgs { f, i } = self;
+        i(&args);
+        f.call(args)
+    }
+}
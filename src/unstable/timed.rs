@@ -0,0 +1,107 @@
+use std::time::{Duration, Instant};
+
+/// Measure execution time of a function.
+///
+/// Takes function `f` and returns function that calls `f` and returns its
+/// output together with the time the call took (measured with [`Instant`]),
+/// in other words something _like_ `|a: A| { let start = Instant::now(); let
+/// out = f(a); (out, start.elapsed()) }`.
+///
+/// This is useful to profile individual stages of a pipeline.
+///
+/// **NOTE**: this function is under `#[cfg(feature = "std")]`
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::timed;
+/// use std::time::Duration;
+///
+/// let sum = |n: u64| (0..n).sum::<u64>();
+/// let timed_sum = timed(sum);
+///
+/// let (res, time) = timed_sum(1000);
+/// assert_eq!(res, sum(1000));
+/// assert!(time >= Duration::ZERO);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::timed`]
+///
+/// [`FnExt::timed`]: crate::unstable::FnExt::timed
+#[inline]
+pub fn timed<A, F>(f: F) -> Timed<F>
+where
+    F: FnOnce<A>,
+{
+    Timed::new(f)
+}
+
+/// Represents function `F` with measured execution time.
+///
+/// For documentation see [`timed`].
+///
+/// **NOTE**: this struct is under `#[cfg(feature = "std")]`
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timed<F> {
+    f: F,
+}
+
+impl<F> Timed<F> {
+    /// Creates version of the function `f` with measured execution time.
+    ///
+    /// It's preferred to use [`timed`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<A>,
+    {
+        Timed { f }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<A, F> FnOnce<A> for Timed<F>
+where
+    F: FnOnce<A>,
+{
+    type Output = (F::Output, Duration);
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let start = Instant::now();
+        let res = self.f.call_once(args);
+        (res, start.elapsed())
+    }
+}
+
+impl<A, F> FnMut<A> for Timed<F>
+where
+    F: FnMut<A>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let start = Instant::now();
+        let res = self.f.call_mut(args);
+        (res, start.elapsed())
+    }
+}
+
+impl<A, F> Fn<A> for Timed<F>
+where
+    F: Fn<A>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let start = Instant::now();
+        let res = self.f.call(args);
+        (res, start.elapsed())
+    }
+}
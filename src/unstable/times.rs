@@ -0,0 +1,125 @@
+/// Repeat function `n` times.
+///
+/// Takes function `f` and returns function that applies `f` to its argument
+/// exactly `n` times (in other words something _like_ `|a: A|
+/// f(f(...f(a)...))`). `times(f, 0)` is the identity function.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::times;
+///
+/// let double = |x: i32| x * 2;
+/// let times_8 = times(double, 3);
+/// assert_eq!(times_8(1), 8);
+///
+/// let id = times(double, 0);
+/// assert_eq!(id(1), 1);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::iterate`]
+/// - extension on all functions: [`FnExt::times`]
+///
+/// [`FnExt::times`]: crate::unstable::FnExt::times
+/// [`fntools::iterate`]: crate::iterate
+#[inline]
+pub fn times<A, F>(f: F, n: usize) -> Times<F>
+where
+    F: FnMut(A) -> A,
+{
+    Times::new(f, n)
+}
+
+/// Represents function `F` repeated `n` times.
+///
+/// For documentation see [`times`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Times<F> {
+    f: F,
+    n: usize,
+}
+
+impl<F> Times<F> {
+    /// Creates function that applies `f` `n` times.
+    ///
+    /// It's preferred to use [`times`] instead.
+    #[inline]
+    pub fn new<A>(f: F, n: usize) -> Self
+    where
+        F: FnMut(A) -> A,
+    {
+        Times { f, n }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<A, F> FnOnce<(A,)> for Times<F>
+where
+    F: FnMut(A) -> A,
+{
+    type Output = A;
+
+    #[inline]
+    extern "rust-call" fn call_once(mut self, args: (A,)) -> Self::Output { self.call_mut(args) }
+}
+
+impl<A, F> FnMut<(A,)> for Times<F>
+where
+    F: FnMut(A) -> A,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+        let Times { f, n } = self;
+        (0..*n).fold(a, |acc, _| f(acc))
+    }
+}
+
+impl<A, F> Fn<(A,)> for Times<F>
+where
+    F: Fn(A) -> A,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+        let Times { f, n } = self;
+        (0..*n).fold(a, |acc, _| f(acc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::FnExt;
+
+    #[test]
+    fn clone() {
+        let suffix = String::from("!");
+        let shout = move |s: String| s + &suffix;
+        let fun = shout.times(2);
+        let clone = fun.clone();
+
+        assert_eq!(fun(String::from("hi")), "hi!!");
+        assert_eq!(clone(String::from("hey")), "hey!!");
+    }
+
+    #[test]
+    fn fn_mut() {
+        let mut calls = 0;
+        let inc = |x: u32| {
+            calls += 1;
+            x + 1
+        };
+        let mut fun = inc.times(3);
+
+        assert_eq!(fun.call_mut((0,)), 3);
+        assert_eq!(fun.call_mut((10,)), 13);
+        drop(fun);
+        assert_eq!(calls, 6);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::tuple::take::TupleTake;
+
+/// Uncurring.
+///
+/// Takes curried function `f` (i.e. function that takes one argument and
+/// returns a function that takes the next argument and so on) and returns
+/// function that takes all arguments at once. This is the inverse of
+/// [`curry`].
+///
+/// ## Examples
+/// ```
+/// use fntools::unstable::uncurry;
+///
+/// let add = |a: i32| move |b: i32| a + b;
+/// let add = uncurry(add);
+///
+/// assert_eq!(add(2, 3), 5);
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::uncurry`]
+///
+/// [`curry`]: crate::unstable::curry
+/// [`FnExt::uncurry`]: crate::unstable::FnExt::uncurry
+#[inline]
+pub fn uncurry<F>(f: F) -> Uncurry<F> { Uncurry::new(f) }
+
+/// Represents uncurried function `F`.
+///
+/// For documentation see [`uncurry`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uncurry<F> {
+    f: F,
+}
+
+impl<F> Uncurry<F> {
+    /// Creates uncurried function `f`.
+    ///
+    /// It's preferred to use [`uncurry`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self { Uncurry { f } }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+/// Curried function that can be called with all of the `Args` one by one.
+///
+/// `()` means that there is nothing left to apply, so anything is a curried
+/// function of no arguments.
+pub trait CallCurried<Args> {
+    /// Result of applying all of the `Args`.
+    type Output;
+
+    /// Applies `args` one by one.
+    fn call_curried(self, args: Args) -> Self::Output;
+}
+
+impl<T> CallCurried<()> for T {
+    type Output = T;
+
+    #[inline]
+    fn call_curried(self, (): ()) -> Self::Output { self }
+}
+
+impl<F, Args> CallCurried<Args> for F
+where
+    Args: TupleTake,
+    F: FnOnce<(Args::Take,)>,
+    F::Output: CallCurried<Args::Rem>,
+{
+    type Output = <F::Output as CallCurried<Args::Rem>>::Output;
+
+    #[inline]
+    fn call_curried(self, args: Args) -> Self::Output {
+        let (take, rem) = args.take();
+        self.call_once((take,)).call_curried(rem)
+    }
+}
+
+impl<F, Args> FnOnce<Args> for Uncurry<F>
+where
+    Args: TupleTake,
+    F: FnOnce<(Args::Take,)>,
+    F::Output: CallCurried<Args::Rem>,
+{
+    type Output = <F::Output as CallCurried<Args::Rem>>::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: Args) -> Self::Output {
+        let (take, rem) = args.take();
+        self.f.call_once((take,)).call_curried(rem)
+    }
+}
+
+impl<F, Args> FnMut<Args> for Uncurry<F>
+where
+    Args: TupleTake,
+    F: FnMut<(Args::Take,)>,
+    F::Output: CallCurried<Args::Rem>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+        let (take, rem) = args.take();
+        self.f.call_mut((take,)).call_curried(rem)
+    }
+}
+
+impl<F, Args> Fn<Args> for Uncurry<F>
+where
+    Args: TupleTake,
+    F: Fn<(Args::Take,)>,
+    F::Output: CallCurried<Args::Rem>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+        let (take, rem) = args.take();
+        self.f.call((take,)).call_curried(rem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::{uncurry, FnExt};
+
+    #[test]
+    fn roundtrip() {
+        let fun = |a: i32, b: String, c: char| format!("{}{}{}", a, b, c);
+        let fun = fun.curry().uncurry();
+
+        assert_eq!(fun(1, String::from("-"), 'a'), "1-a");
+    }
+
+    #[test]
+    fn partial() {
+        let fun = |a: i32| move |b: i32| move |c: i32| a * 100 + b * 10 + c;
+        let partial = uncurry(fun)(1, 2);
+
+        assert_eq!(partial(3), 123);
+        assert_eq!(uncurry(fun)(4, 5, 6), 456);
+    }
+}
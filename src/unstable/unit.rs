@@ -21,7 +21,7 @@ where
 ///
 /// See [`unit`] for documentation.
 #[must_use = "function combinators are lazy and do nothing unless called"]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Unit<F>(F);
 
 impl<F> Unit<F> {
@@ -76,3 +76,48 @@ where
     #[inline]
     extern "rust-call" fn call(&self, args: A) -> Self::Output { self.0.call(args); }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use crate::unstable::unit;
+
+    #[test]
+    fn clone() {
+        let log = RefCell::new(Vec::new());
+        let log_ref = &log;
+        let prefix = String::from("#");
+        let push = move |x: i32| log_ref.borrow_mut().push(format!("{}{}", prefix, x));
+        let fun = unit(push);
+        let clone = fun.clone();
+
+        fun(1);
+        clone(2);
+
+        assert_eq!(*log.borrow(), ["#1", "#2"]);
+    }
+
+    #[test]
+    fn copy() {
+        let sum = Cell::new(0);
+        let add = |a: i32| {
+            sum.set(sum.get() + a);
+            sum.get()
+        };
+        let fun = unit(add);
+        let copy = fun;
+
+        fun(1);
+        copy(2);
+
+        assert_eq!(sum.get(), 3);
+    }
+
+    #[test]
+    fn debug() {
+        let add: fn(i32, i32) -> i32 = |a, b| a + b;
+
+        assert_eq!(format!("{:?}", unit(add)), format!("Unit({:?})", add));
+    }
+}
@@ -1,3 +1,10 @@
+use core::{
+    fmt::{Debug, Error, Formatter},
+    marker::PhantomData,
+};
+
+use crate::tuple::flatten::FlattenTuple;
+
 /// Turns functions of n arguments into function of 1 argument —
 /// tuple of argument of the original function.
 ///
@@ -22,7 +29,7 @@ where
 /// Turns functions of n arguments into function of 1 argument —
 /// tuple of argument of the original function.
 #[must_use = "function combinators are lazy and do nothing unless called"]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Untuple<F>(F);
 
 impl<F> Untuple<F> {
@@ -90,3 +97,206 @@ where
         res
     }
 }
+
+/// Call function `f` with a tuple of arguments, i.e. "spread" the tuple into
+/// positional arguments.
+///
+/// This is the same as `untuple(f)(args)`.
+///
+/// ## Examples
+///
+/// ```
+/// use fntools::unstable::spread;
+///
+/// let add = |a: i32, b: i32| a + b;
+/// assert_eq!(spread(add, (2, 3)), 5);
+///
+/// let point = (1, 2, 3);
+/// let fmt = |x: i32, y: i32, z: i32| format!("({}, {}, {})", x, y, z);
+/// assert_eq!(spread(fmt, point), "(1, 2, 3)");
+/// ```
+///
+/// See also:
+/// - extension on all functions: [`FnExt::call_with`]
+///
+/// [`FnExt::call_with`]: crate::unstable::FnExt::call_with
+#[inline]
+pub fn spread<A, F>(f: F, args: A) -> F::Output
+where
+    F: FnOnce<A>,
+{
+    f.call_once(args)
+}
+
+/// Turns functions of n arguments into function of 1 argument — nested tuple
+/// which is flattened (see [`FlattenTuple`]) into arguments of the original
+/// function.
+///
+/// ## Examples
+///
+/// ```
+/// use fntools::unstable::untuple_flat;
+///
+/// let sum = |a: i32, b: i32, c: i32| a + b + c;
+/// let fun = untuple_flat(sum);
+/// assert_eq!(fun(((1, 2), 3)), 6);
+/// ```
+///
+/// [`FlattenTuple`]: crate::tuple::flatten::FlattenTuple
+#[inline]
+pub fn untuple_flat<Shape, F>(f: F) -> UntupleFlat<F, Shape> { UntupleFlat::new(f) }
+
+/// Turns functions of n arguments into function of 1 argument — nested tuple
+/// which is flattened into arguments of the original function.
+///
+/// For documentation see [`untuple_flat`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct UntupleFlat<F, Shape> {
+    f: F,
+    marker: PhantomData<fn() -> Shape>,
+}
+
+impl<F, Shape> UntupleFlat<F, Shape> {
+    /// Turns functions of n arguments into function of 1 argument — nested
+    /// tuple which is flattened into arguments of the original function.
+    ///
+    /// It's preferred to use [`untuple_flat`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        UntupleFlat {
+            f,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F { self.f }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+}
+
+impl<F, Shape> Debug for UntupleFlat<F, Shape>
+where
+    F: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_tuple("UntupleFlat").field(&self.f).finish()
+    }
+}
+
+impl<F, Shape> Clone for UntupleFlat<F, Shape>
+where
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        UntupleFlat {
+            f: self.f.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F, Shape> Copy for UntupleFlat<F, Shape> where F: Copy {}
+
+impl<T, F, Shape> FnOnce<(T,)> for UntupleFlat<F, Shape>
+where
+    T: FlattenTuple<Shape>,
+    F: FnOnce<T::Res>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (args,): (T,)) -> Self::Output {
+        self.f.call_once(args.flatten())
+    }
+}
+
+impl<T, F, Shape> FnMut<(T,)> for UntupleFlat<F, Shape>
+where
+    T: FlattenTuple<Shape>,
+    F: FnMut<T::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (args,): (T,)) -> Self::Output {
+        self.f.call_mut(args.flatten())
+    }
+}
+
+impl<T, F, Shape> Fn<(T,)> for UntupleFlat<F, Shape>
+where
+    T: FlattenTuple<Shape>,
+    F: Fn<T::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (args,): (T,)) -> Self::Output { self.f.call(args.flatten()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unstable::{untuple, FnExt};
+
+    #[test]
+    fn clone() {
+        let sep = String::from("-");
+        let join = move |a: i32, b: i32| format!("{}{}{}", a, sep, b);
+        let fun = untuple(join);
+        let clone = fun.clone();
+
+        assert_eq!(fun((1, 2)), "1-2");
+        assert_eq!(clone((1, 2)), fun((1, 2)));
+    }
+
+    #[test]
+    fn copy() {
+        let fun = untuple(|a: i32, b: i32| a * b);
+        let copy = fun;
+
+        assert_eq!(copy((3, 4)), 12);
+        assert_eq!(copy((3, 4)), fun((3, 4)));
+    }
+
+    #[test]
+    fn ten_element_tuples() {
+        let spread = |x: u32| {
+            (
+                x,
+                x + 1,
+                x + 2,
+                x + 3,
+                x + 4,
+                x + 5,
+                x + 6,
+                x + 7,
+                x + 8,
+                x + 9,
+            )
+        };
+        let sum = |a: u32, b, c, d, e, f, g, h, i, j| a + b + c + d + e + f + g + h + i + j;
+        let swap = |a: u32, b: u32, c, d, e, f, g, h, i, j| (j, i, h, g, f, e, d, c, b, a);
+
+        assert_eq!(spread.chain_ut(sum)(0), 45);
+        assert_eq!(spread.chain_ut(swap).chain_ut(sum)(1), 55);
+        assert_eq!(spread.chain_ut(swap)(0), (9, 8, 7, 6, 5, 4, 3, 2, 1, 0));
+    }
+
+    #[test]
+    fn chain_flat() {
+        let nested = |a: i32| (a, (a + 1, a + 2), a + 3);
+        let sum = |a: i32, b: i32, c: i32, d: i32| a + b + c + d;
+        let fun = nested.chain_flat(sum);
+
+        assert_eq!(fun(0), 6);
+        assert_eq!(fun(1), 10);
+    }
+
+    #[test]
+    fn debug() {
+        let mul: fn(i32, i32) -> i32 = |a, b| a * b;
+
+        assert_eq!(format!("{:?}", untuple(mul)), format!("Untuple({:?})", mul));
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::{string::String, vec::Vec};
+use core::{any::type_name, fmt::Write};
+
+use crate::unstable::{Chain, Compose};
+
+/// Stages of a (possibly nested) pipeline in the data flow order.
+///
+/// Any function is a single stage, [`Chain`]s and [`Compose`]s are unwrapped
+/// into their inner functions.
+trait Stages {
+    fn stages(&self, out: &mut Vec<&'static str>);
+}
+
+impl<T> Stages for T {
+    #[inline]
+    default fn stages(&self, out: &mut Vec<&'static str>) { out.push(type_name::<T>()) }
+}
+
+impl<F, G> Stages for Chain<F, G> {
+    #[inline]
+    fn stages(&self, out: &mut Vec<&'static str>) {
+        let (f, g) = self.as_inner();
+        f.stages(out);
+        g.stages(out);
+    }
+}
+
+impl<F, G> Stages for Compose<F, G> {
+    #[inline]
+    fn stages(&self, out: &mut Vec<&'static str>) {
+        let (f, g) = self.as_inner();
+        g.stages(out);
+        f.stages(out);
+    }
+}
+
+/// Builds Graphviz DOT digraph with a node for every stage and edges in the
+/// data flow order.
+fn to_dot<S: Stages>(pipeline: &S) -> String {
+    let mut stages = Vec::new();
+    pipeline.stages(&mut stages);
+
+    let mut dot = String::from("digraph {\n");
+    for (i, stage) in stages.iter().enumerate() {
+        let label = stage.replace('\\', "\\\\").replace('"', "\\\"");
+        writeln!(dot, "    {} [label=\"{}\"];", i, label).unwrap();
+    }
+    for i in 1..stages.len() {
+        writeln!(dot, "    {} -> {};", i - 1, i).unwrap();
+    }
+    dot.push('}');
+    dot
+}
+
+impl<F, G> Chain<F, G> {
+    /// Returns [Graphviz DOT] description of the pipeline.
+    ///
+    /// Every stage becomes a node (labeled with the type name of the stage)
+    /// and edges show the data flow order. Nested [`Chain`]s and [`Compose`]s
+    /// are unwrapped into their stages.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "visualize")]`
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// fn parse(s: &str) -> i32 { s.parse().unwrap() }
+    /// fn double(x: i32) -> i32 { x * 2 }
+    ///
+    /// let dot = parse.chain(double).to_dot();
+    ///
+    /// assert!(dot.starts_with("digraph {"));
+    /// assert!(dot.contains("::parse\"];"));
+    /// assert!(dot.contains("::double\"];"));
+    /// assert!(dot.contains("0 -> 1;"));
+    /// ```
+    ///
+    /// [Graphviz DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String { to_dot(self) }
+}
+
+impl<F, G> Compose<F, G> {
+    /// Returns [Graphviz DOT] description of the pipeline.
+    ///
+    /// Every stage becomes a node (labeled with the type name of the stage)
+    /// and edges show the data flow order (i.e. `g` comes before `f`). Nested
+    /// [`Chain`]s and [`Compose`]s are unwrapped into their stages.
+    ///
+    /// **NOTE**: this method is under `#[cfg(feature = "visualize")]`
+    ///
+    /// See [`Chain::to_dot`] for examples.
+    ///
+    /// [Graphviz DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String { to_dot(self) }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::unstable::{chain, compose};
+
+    fn first(x: i32) -> i32 { x + 1 }
+    fn second(x: i32) -> i32 { x * 2 }
+    fn third(x: i32) -> String { x.to_string() }
+
+    /// Checks that `dot` describes the `first -> second -> third` pipeline.
+    fn check(dot: &str) {
+        let lines: Vec<&str> = dot.lines().map(str::trim).collect();
+
+        let nodes: Vec<(&str, &str)> = lines
+            .iter()
+            .filter_map(|l| l.split_once(" [label=\""))
+            .map(|(id, label)| (id, label.strip_suffix("\"];").unwrap()))
+            .collect();
+        let (ids, labels): (Vec<_>, Vec<_>) = nodes.into_iter().unzip();
+        assert_eq!(ids, ["0", "1", "2"], "{}", dot);
+        assert!(labels[0].ends_with("first"), "{}", dot);
+        assert!(labels[1].ends_with("second"), "{}", dot);
+        assert!(labels[2].ends_with("third"), "{}", dot);
+
+        let edges: Vec<&str> = lines.iter().copied().filter(|l| l.contains("->")).collect();
+        assert_eq!(edges, ["0 -> 1;", "1 -> 2;"], "{}", dot);
+    }
+
+    #[test]
+    fn chain_three_stages() {
+        check(&chain(first, chain(second, third)).to_dot());
+        check(&chain(chain(first, second), third).to_dot());
+    }
+
+    #[test]
+    fn compose_three_stages() {
+        check(&compose(third, compose(second, first)).to_dot());
+        check(&compose(compose(third, second), first).to_dot());
+        check(&compose(third, chain(first, second)).to_dot());
+    }
+}